@@ -82,7 +82,9 @@ pub fn main() -> Result<(), String> {
     }
 
     dbg!(output);
-    dbg!(subcommand);
+    if let Some(Subcommand { r, g, b }) = subcommand {
+        println!("rgb: {}, {}, {}", r, g, b);
+    }
 
     Ok(())
 }
@@ -1,9 +1,31 @@
+use std::collections::VecDeque;
 #[cfg(not(any(test, feature = "dyn_iter")))]
 use std::env::Args;
+use std::ffi::OsString;
 
 use crate::part::{InputPart, InputPartLd};
 use crate::TokenKind;
 
+#[cfg(any(test, feature = "dyn_iter"))]
+type Iter = Box<dyn Iterator<Item = String>>;
+#[cfg(not(any(test, feature = "dyn_iter")))]
+enum Iter {
+    Args(Args),
+    Collected(std::vec::IntoIter<String>),
+}
+
+#[cfg(not(any(test, feature = "dyn_iter")))]
+impl Iterator for Iter {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        match self {
+            Iter::Args(args) => args.next(),
+            Iter::Collected(args) => args.next(),
+        }
+    }
+}
+
 /// The default input type for argument parsing. This is generic over its
 /// iterator type and can be used with [`std::env::args`]. See
 /// [`ArgsInput::new()`] for more information.
@@ -13,16 +35,37 @@ use crate::TokenKind;
 /// re-allocate.
 pub struct ArgsInput {
     current: Option<(usize, usize, TokenKind)>,
-
-    #[cfg(any(test, feature = "dyn_iter"))]
-    iter: Box<dyn Iterator<Item = String>>,
-    #[cfg(not(any(test, feature = "dyn_iter")))]
-    iter: Args,
+    iter: Iter,
 
     buf: String,
+    /// The start of the current argument in `buf`
+    arg_start: usize,
+    /// The current argument if it isn't valid Unicode
+    current_raw: Option<OsString>,
+    /// The argument returned by the last `read_arg` call if it isn't valid
+    /// Unicode
+    last_raw: Option<OsString>,
+    /// The number of arguments read from `iter`
+    iter_count: usize,
+    /// The arguments from `iter` that aren't valid Unicode, with their index
+    raw_args: VecDeque<(usize, OsString)>,
     ignore_dashes: bool,
+    value_separators: Vec<char>,
+    arg_index: usize,
+
+    collect_unknown: bool,
+    unknown: Vec<String>,
+
+    response_files: bool,
+    pending: VecDeque<(String, Option<OsString>)>,
+
+    collect_all_errors: bool,
+    errors: Vec<BoxedError>,
 }
 
+/// An error stored with [`ArgsInput::store_error()`]
+pub type BoxedError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
 #[cfg(any(test, feature = "dyn_iter"))]
 impl ArgsInput {
     /// Creates a new instance of this input.
@@ -37,19 +80,74 @@ impl ArgsInput {
     /// You probably want to discard the first argument in this case, which is
     /// just the path to the executable.
     pub fn new<I: Iterator<Item = String> + 'static>(iter: I) -> Self {
-        let mut iter = Box::new(iter);
-        match iter.next() {
-            Some(buf) => Self {
-                current: Some(Self::trim_leading_dashes(false, &buf, 0)),
-                iter,
-                buf,
-                ignore_dashes: false,
-            },
-            None => {
-                Self { current: None, iter, buf: String::new(), ignore_dashes: false }
+        Self::with_iter(Box::new(iter))
+    }
+
+    /// Creates a new instance from a command line in a single string. Unlike
+    /// `ArgsInput::from`, which splits the string at every space, this splits
+    /// it at whitespace like a shell:
+    ///
+    /// - Text in single quotes is taken literally
+    /// - In double quotes, a backslash escapes `"` and `\\`
+    /// - Outside of quotes, a backslash escapes any character
+    ///
+    /// ### Example:
+    ///
+    /// ```
+    /// # use palex::ArgsInput;
+    /// let mut input = ArgsInput::from_shell_str(r#"--name "a b" -x"#);
+    /// assert_eq!(input.eat_two_dashes("name"), Some("name"));
+    /// assert_eq!(input.bump_argument(), Some("a b"));
+    /// assert_eq!(input.eat_one_dash("x"), Some("x"));
+    /// ```
+    pub fn from_shell_str(s: &str) -> Self {
+        ArgsInput::new(split_shell_words(s).into_iter())
+    }
+}
+
+/// Splits a string into arguments, respecting quotes and backslash escapes.
+/// An unterminated quote extends to the end of the string.
+#[cfg(any(test, feature = "dyn_iter"))]
+fn split_shell_words(s: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {
+                words.extend(word.take());
+            }
+            '\'' => {
+                let word = word.get_or_insert_with(String::new);
+                word.extend(chars.by_ref().take_while(|&c| c != '\''));
+            }
+            '"' => {
+                let word = word.get_or_insert_with(String::new);
+                while let Some(c) = chars.next() {
+                    match c {
+                        '"' => break,
+                        '\\' => match chars.next() {
+                            Some(c @ ('"' | '\\')) => word.push(c),
+                            Some(c) => {
+                                word.push('\\');
+                                word.push(c);
+                            }
+                            None => word.push('\\'),
+                        },
+                        c => word.push(c),
+                    }
+                }
+            }
+            '\\' => {
+                let word = word.get_or_insert_with(String::new);
+                word.extend(chars.next());
             }
+            c => word.get_or_insert_with(String::new).push(c),
         }
     }
+    words.extend(word);
+    words
 }
 
 #[cfg(any(test, feature = "dyn_iter"))]
@@ -73,21 +171,149 @@ impl ArgsInput {
     /// just the path to the executable.
     pub fn from_args() -> Self {
         #[cfg(any(test, feature = "dyn_iter"))]
-        let mut iter = Box::new(std::env::args());
+        let iter = Box::new(std::env::args());
+        #[cfg(not(any(test, feature = "dyn_iter")))]
+        let iter = Iter::Args(std::env::args());
+
+        Self::with_iter(iter)
+    }
+
+    /// Creates a new instance from the command-line arguments. Unlike
+    /// [`ArgsInput::from_args()`], this doesn't panic if an argument isn't
+    /// valid Unicode. See [`ArgsInput::from_os_args()`] for details.
+    ///
+    /// ### Example:
+    ///
+    /// ```
+    /// # use palex::ArgsInput;
+    /// let mut _input = ArgsInput::from_args_os();
+    /// ```
+    pub fn from_args_os() -> Self {
+        Self::from_os_args(std::env::args_os())
+    }
+
+    /// Creates a new instance from a list of `OsString`s. Arguments that aren't
+    /// valid Unicode are converted lossily, but the original is preserved: As
+    /// long as such an argument is the current argument and nothing was
+    /// consumed from it, it can be retrieved with
+    /// [`ArgsInput::eat_value_os()`].
+    pub fn from_os_args(args: impl IntoIterator<Item = OsString>) -> Self {
+        let mut raw_args = VecDeque::new();
+        let args: Vec<String> = args
+            .into_iter()
+            .enumerate()
+            .map(|(i, arg)| {
+                arg.into_string().unwrap_or_else(|arg| {
+                    let lossy = arg.to_string_lossy().into_owned();
+                    raw_args.push_back((i, arg));
+                    lossy
+                })
+            })
+            .collect();
+
+        #[cfg(any(test, feature = "dyn_iter"))]
+        let iter: Iter = Box::new(args.into_iter());
         #[cfg(not(any(test, feature = "dyn_iter")))]
-        let mut iter = std::env::args();
-
-        match iter.next() {
-            Some(buf) => Self {
-                current: Some(Self::trim_leading_dashes(false, &buf, 0)),
-                iter,
-                buf,
-                ignore_dashes: false,
-            },
-            None => {
-                Self { current: None, iter, buf: String::new(), ignore_dashes: false }
+        let iter = Iter::Collected(args.into_iter());
+
+        let mut input = Self::with_iter(iter);
+        if let Some((0, _)) = raw_args.front() {
+            input.current_raw = raw_args.pop_front().map(|(_, arg)| arg);
+        }
+        input.raw_args = raw_args;
+        input
+    }
+
+    fn with_iter(mut iter: Iter) -> Self {
+        let (current, buf) = match iter.next() {
+            Some(buf) => (Some(Self::trim_leading_dashes(false, &buf, 0)), buf),
+            None => (None, String::new()),
+        };
+        let iter_count = if current.is_some() { 1 } else { 0 };
+        Self {
+            current,
+            iter,
+            buf,
+            arg_start: 0,
+            current_raw: None,
+            last_raw: None,
+            iter_count,
+            raw_args: VecDeque::new(),
+            ignore_dashes: false,
+            value_separators: vec!['='],
+            arg_index: 0,
+            collect_unknown: false,
+            unknown: Vec::new(),
+            response_files: false,
+            pending: VecDeque::new(),
+            collect_all_errors: false,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Enables or disables response files. When enabled, an argument of the
+    /// form `@path` is replaced with the whitespace-separated tokens in the
+    /// file at `path`. If the file can't be read, the argument is left
+    /// unchanged. Tokens read from a response file are not expanded again.
+    ///
+    /// ### Example:
+    ///
+    /// ```
+    /// # use palex::ArgsInput;
+    /// let mut _input = ArgsInput::from_args().with_response_files(true);
+    /// ```
+    pub fn with_response_files(mut self, enabled: bool) -> Self {
+        self.response_files = enabled;
+
+        // the first argument was already read, so it is expanded here
+        if enabled
+            && self.buf.starts_with('@')
+            && self.current_raw.is_none()
+            && matches!(self.current, Some((0, 0, _)))
+        {
+            let arg = std::mem::take(&mut self.buf);
+            match self.expand_response_file(arg) {
+                Some(buf) => {
+                    self.current =
+                        Some(Self::trim_leading_dashes(self.ignore_dashes, &buf, 0));
+                    self.buf = buf;
+                }
+                None => self.current = None,
+            }
+        }
+        self
+    }
+
+    /// Returns the next argument, expanding response files if enabled. If the
+    /// argument isn't valid Unicode, the original is stored in `last_raw`.
+    fn read_arg(&mut self) -> Option<String> {
+        self.last_raw = None;
+        if let Some((arg, raw)) = self.pending.pop_front() {
+            self.last_raw = raw;
+            return Some(arg);
+        }
+        let arg = self.iter.next()?;
+        let index = self.iter_count;
+        self.iter_count += 1;
+        if matches!(self.raw_args.front(), Some(&(i, _)) if i == index) {
+            self.last_raw = self.raw_args.pop_front().map(|(_, raw)| raw);
+        }
+        if self.response_files && self.last_raw.is_none() {
+            self.expand_response_file(arg)
+        } else {
+            Some(arg)
+        }
+    }
+
+    fn expand_response_file(&mut self, arg: String) -> Option<String> {
+        if let Some(path) = arg.strip_prefix('@') {
+            if let Ok(content) = std::fs::read_to_string(path) {
+                let args = content.split_whitespace().map(|arg| (arg.to_string(), None));
+                self.pending.extend(args);
+                return self.read_arg();
             }
         }
+        Some(arg)
     }
 
     fn trim_leading_dashes(
@@ -110,20 +336,20 @@ impl ArgsInput {
         match kind {
             TokenKind::NoDash => {}
             TokenKind::OneDash => {
-                if self.buf[current..].starts_with('=') {
-                    return (current + 1, current + 1, TokenKind::AfterEquals);
+                if let Some(len) = self.separator_len(current) {
+                    return (current + len, current + len, TokenKind::AfterEquals);
                 } else {
                     return (current, current, TokenKind::AfterOneDash);
                 }
             }
             TokenKind::TwoDashes => {
-                if self.buf[current..].starts_with('=') {
-                    return (current + 1, current + 1, TokenKind::AfterEquals);
+                if let Some(len) = self.separator_len(current) {
+                    return (current + len, current + len, TokenKind::AfterEquals);
                 }
             }
             TokenKind::AfterOneDash => {
-                if self.buf[current..].starts_with('=') {
-                    return (current + 1, current + 1, TokenKind::AfterEquals);
+                if let Some(len) = self.separator_len(current) {
+                    return (current + len, current + len, TokenKind::AfterEquals);
                 }
             }
             TokenKind::AfterEquals => {}
@@ -131,6 +357,17 @@ impl ArgsInput {
         (current, current, kind)
     }
 
+    /// Returns the length in bytes of the value separator at `current`, if
+    /// there is one. Separators can be longer than one byte, e.g. `→`.
+    fn separator_len(&self, current: usize) -> Option<usize> {
+        let c = self.buf[current..].chars().next()?;
+        if self.value_separators.contains(&c) {
+            Some(c.len_utf8())
+        } else {
+            None
+        }
+    }
+
     /// Returns the current token as string slice and the [`TokenKind`] of the
     /// current token, or [None] if the input is empty.
     ///
@@ -146,6 +383,20 @@ impl ArgsInput {
         self.current.map(|(_, i, _)| &self.buf[i..])
     }
 
+    /// Returns the rest of the current argument as it was typed, or [None] if
+    /// the input is empty. Unlike
+    /// [`ArgsInput::current_str_with_leading_dashes()`], the rest of a group
+    /// of short flags gets a leading dash, e.g. `-bc` for `-abc` after `a`
+    /// was parsed.
+    fn current_as_typed(&self) -> Option<String> {
+        let (_, cwd, kind) = self.current?;
+        let mut arg = self.buf[cwd..].to_string();
+        if kind == TokenKind::AfterOneDash {
+            arg.insert(0, '-');
+        }
+        Some(arg)
+    }
+
     /// Bumps the current token by `len` bytes.
     ///
     /// Leading dashes are ignored, e.g. bumping the argument `--foo` by one
@@ -169,13 +420,18 @@ impl ArgsInput {
             *current += len;
 
             if current_len == len {
-                match self.iter.next() {
+                self.arg_index += 1;
+                let current = *current;
+                let arg = self.read_arg();
+                self.current_raw = self.last_raw.take();
+                match arg {
                     Some(s) => {
                         self.buf.push_str(&s);
+                        self.arg_start = current;
                         self.current = Some(Self::trim_leading_dashes(
                             self.ignore_dashes,
                             &s,
-                            *current,
+                            current,
                         ));
                     }
                     None => self.current = None,
@@ -207,15 +463,21 @@ impl ArgsInput {
             }
 
             let prev_current = *cwd;
-            *current += len;
             *cwd += len;
+            // the leading dashes might not have been consumed completely
+            *current = (*current).max(*cwd);
 
             if current_len == len {
-                match self.iter.next() {
+                self.arg_index += 1;
+                let cwd = *cwd;
+                let arg = self.read_arg();
+                self.current_raw = self.last_raw.take();
+                match arg {
                     Some(s) => {
                         self.buf.push_str(&s);
+                        self.arg_start = cwd;
                         self.current =
-                            Some(Self::trim_leading_dashes(self.ignore_dashes, &s, *cwd));
+                            Some(Self::trim_leading_dashes(self.ignore_dashes, &s, cwd));
                     }
                     None => self.current = None,
                 }
@@ -230,6 +492,31 @@ impl ArgsInput {
         }
     }
 
+    /// Returns the complete current argument as it was provided, including
+    /// leading dashes and the parts that were already consumed. For example,
+    /// after `a` was eaten from `-abc`, this still returns `-abc`. This is
+    /// useful for passing arguments through to another program.
+    pub fn current_argument_raw(&self) -> Option<&str> {
+        self.current.map(|_| &self.buf[self.arg_start..])
+    }
+
+    /// If the current argument isn't valid Unicode and nothing was consumed
+    /// from it, it is bumped and the original is returned. This only works for
+    /// arguments that don't start with a dash, and only if the input was
+    /// created with [`ArgsInput::from_args_os()`] or
+    /// [`ArgsInput::from_os_args()`]. Note that a value after an equals sign,
+    /// e.g. in `--path=<value>`, is not preserved.
+    pub fn eat_value_os(&mut self) -> Option<OsString> {
+        match self.current {
+            Some((_, cwd, TokenKind::NoDash)) if cwd == self.arg_start => {
+                let raw = self.current_raw.take()?;
+                self.bump_argument();
+                Some(raw)
+            }
+            _ => None,
+        }
+    }
+
     /// Bumps the current argument (including leading dashes) completely.
     pub fn bump_argument(&mut self) -> Option<&str> {
         if let Some((i, _, _)) = self.current {
@@ -261,6 +548,187 @@ impl ArgsInput {
         self.ignore_dashes
     }
 
+    /// Sets the character that separates a flag from a value within the same
+    /// argument. The default is `=`, e.g. `--out=foo`. With `:`, the argument
+    /// `--out:foo` is parsed as the flag `out` and the value `foo`.
+    pub fn set_value_separator(&mut self, separator: char) {
+        self.value_separators = vec![separator];
+    }
+
+    /// Sets the characters that separate a flag from a value within the same
+    /// argument. Any of them is accepted, e.g. with `['=', ':']` both
+    /// `--out=foo` and `--out:foo` are parsed as the flag `out` and the value
+    /// `foo`.
+    pub fn set_value_separators(&mut self, separators: &[char]) {
+        self.value_separators = separators.to_vec();
+    }
+
+    /// Returns the characters that separate a flag from a value within the
+    /// same argument. See [`ArgsInput::set_value_separators()`].
+    pub fn value_separators(&self) -> &[char] {
+        &self.value_separators
+    }
+
+    /// Sets whether unknown arguments should be collected. When `true`,
+    /// unknown arguments can be stashed with [`ArgsInput::stash_unknown()`]
+    /// instead of producing an error, and retrieved later with
+    /// [`ArgsInput::take_unknown()`].
+    pub fn set_collect_unknown(&mut self, collect: bool) {
+        self.collect_unknown = collect;
+    }
+
+    /// Returns whether unknown arguments should be collected. See
+    /// [`ArgsInput::set_collect_unknown()`].
+    pub fn collect_unknown(&self) -> bool {
+        self.collect_unknown
+    }
+
+    /// Bumps the rest of the current argument (including leading dashes) and
+    /// saves it in the list of unknown arguments. Returns the stashed argument,
+    /// or [None] if the input is empty.
+    ///
+    /// In a group of short flags, the rest of the group is stashed with a
+    /// leading dash, e.g. `-b=c` for `-ab=c` after `a` was parsed, so it can
+    /// be parsed as a flag again.
+    pub fn stash_unknown(&mut self) -> Option<&str> {
+        let arg = self.bump_argument_with_leading_dashes()?;
+        self.unknown.push(arg);
+        self.unknown.last().map(String::as_str)
+    }
+
+    /// Bumps the rest of the current argument, including leading dashes, and
+    /// returns it as it was typed. Returns [None] if the input is empty.
+    ///
+    /// Like with [`ArgsInput::stash_unknown()`], the rest of a group of short
+    /// flags is returned with a leading dash.
+    pub fn bump_argument_with_leading_dashes(&mut self) -> Option<String> {
+        let arg = self.current_as_typed()?;
+        let (_, cwd, _) = self.current?;
+        self.bump_with_leading_dashes(self.buf.len() - cwd);
+        Some(arg)
+    }
+
+    /// Returns the unknown arguments that were stashed so far, and clears the
+    /// list.
+    pub fn take_unknown(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.unknown)
+    }
+
+    /// Appends arguments to the list of unknown arguments. This can be used to
+    /// restore arguments that were taken with [`ArgsInput::take_unknown()`].
+    pub fn extend_unknown(&mut self, args: impl IntoIterator<Item = String>) {
+        self.unknown.extend(args);
+    }
+
+    /// Inserts arguments that are read after the current argument. If the
+    /// input is empty, the first inserted argument becomes the current
+    /// argument. This can be used to parse arguments again, e.g. arguments
+    /// collected with [`ArgsInput::take_unknown()`].
+    pub fn insert_args(&mut self, args: Vec<String>) {
+        for arg in args.into_iter().rev() {
+            self.pending.push_front((arg, None));
+        }
+        if self.current.is_none() {
+            if let Some(arg) = self.read_arg() {
+                let current = self.buf.len();
+                self.buf.push_str(&arg);
+                self.arg_start = current;
+                self.current_raw = self.last_raw.take();
+                self.current =
+                    Some(Self::trim_leading_dashes(self.ignore_dashes, &arg, current));
+            }
+        }
+    }
+
+    /// Sets whether errors should be collected. When `true`, a parser can store
+    /// errors with [`ArgsInput::store_error()`] and continue parsing, so that
+    /// all problems are reported at once.
+    pub fn set_collect_all_errors(&mut self, collect: bool) {
+        self.collect_all_errors = collect;
+    }
+
+    /// Returns whether errors should be collected. See
+    /// [`ArgsInput::set_collect_all_errors()`].
+    pub fn collect_all_errors(&self) -> bool {
+        self.collect_all_errors
+    }
+
+    /// Saves an error in the list of stored errors.
+    pub fn store_error(&mut self, error: BoxedError) {
+        self.errors.push(error);
+    }
+
+    /// Returns the number of errors that were stored so far.
+    pub fn stored_error_count(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Returns the errors that were stored so far, and clears the list.
+    pub fn take_stored_errors(&mut self) -> Vec<BoxedError> {
+        std::mem::take(&mut self.errors)
+    }
+
+    /// Returns the remaining input as a single string, e.g. for logging. The
+    /// arguments are separated by spaces; arguments that are empty or contain
+    /// whitespace are quoted. This doesn't consume any input.
+    ///
+    /// Like with [`ArgsInput::stash_unknown()`], the rest of a group of short
+    /// flags is rendered with a leading dash.
+    pub fn rest_as_string(&mut self) -> String {
+        let mut rest: Vec<(String, Option<OsString>)> = Vec::new();
+        while let Some(arg) = self.read_arg() {
+            rest.push((arg, self.last_raw.take()));
+        }
+
+        let mut result = String::new();
+        let current = self.current_as_typed();
+        for (i, arg) in current
+            .as_deref()
+            .into_iter()
+            .chain(rest.iter().map(|(arg, _)| arg.as_str()))
+            .enumerate()
+        {
+            if i > 0 {
+                result.push(' ');
+            }
+            if arg.is_empty() || arg.contains(char::is_whitespace) {
+                result.push('"');
+                for c in arg.chars() {
+                    if c == '"' || c == '\\' {
+                        result.push('\\');
+                    }
+                    result.push(c);
+                }
+                result.push('"');
+            } else {
+                result.push_str(arg);
+            }
+        }
+
+        self.pending = rest.into();
+        result
+    }
+
+    /// Consumes the input and returns the rest of the current argument
+    /// (including leading dashes) followed by all remaining arguments. This is
+    /// useful for passing unconsumed arguments to another program.
+    ///
+    /// Like with [`ArgsInput::stash_unknown()`], the rest of a group of short
+    /// flags is returned with a leading dash.
+    pub fn into_remaining(mut self) -> Vec<String> {
+        let mut remaining: Vec<String> = self.current_as_typed().into_iter().collect();
+        while let Some(arg) = self.read_arg() {
+            remaining.push(arg);
+        }
+        remaining
+    }
+
+    /// Returns the number of arguments that have been fully consumed. This is
+    /// also the index of the current argument.
+    pub fn arg_index(&self) -> usize {
+        self.arg_index
+    }
+
     /// Returns `true` if the input is empty. This means that all arguments have
     /// been fully parsed.
     pub fn is_empty(&self) -> bool {
@@ -297,9 +765,81 @@ impl ArgsInput {
         }
     }
 
+    /// Returns `true` if the next token is a flag or named argument. This is an
+    /// alias for [`ArgsInput::can_parse_dash_argument()`].
+    #[inline]
+    pub fn next_is_flag(&self) -> bool {
+        self.can_parse_dash_argument()
+    }
+
+    /// Returns the current token if the argument doesn't start with dashes,
+    /// without consuming it.
+    pub fn peek_no_dash(&self) -> Option<&str> {
+        match self.current() {
+            Some((s, TokenKind::NoDash)) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns up to `n` arguments after the current argument, without
+    /// consuming anything. Fewer arguments are returned if the input ends
+    /// earlier. Note that the arguments are returned as they are, including
+    /// leading dashes.
+    pub fn peek_following_args(&mut self, n: usize) -> Vec<&str> {
+        // the arguments that were read ahead must stay before the ones that
+        // are pending because of a response file
+        let mut ahead = std::mem::take(&mut self.pending);
+        while ahead.len() < n {
+            match self.read_arg() {
+                Some(arg) => ahead.push_back((arg, self.last_raw.take())),
+                None => break,
+            }
+        }
+        ahead.append(&mut self.pending);
+        self.pending = ahead;
+        self.pending.iter().take(n).map(|(arg, _)| arg.as_str()).collect()
+    }
+
+    /// Eat the current token if `predicate` returns `true` for the token and
+    /// its [`TokenKind`]. This generalizes the `eat_*` methods.
+    ///
+    /// ### Example:
+    ///
+    /// ```no_run
+    /// # use palex::{ArgsInput, TokenKind};
+    /// let mut input = ArgsInput::from_args();
+    /// if let Some(sub) =
+    ///     input.bump_if(|s, kind| kind == TokenKind::NoDash && s.starts_with("sub"))
+    /// {
+    ///     println!("{}", sub);
+    /// }
+    /// ```
+    pub fn bump_if<F: FnOnce(&str, TokenKind) -> bool>(
+        &mut self,
+        predicate: F,
+    ) -> Option<&str> {
+        let (s, kind) = self.current()?;
+        if predicate(s, kind) {
+            let len = s.len();
+            Some(self.bump(len))
+        } else {
+            None
+        }
+    }
+
+    /// Eat the current token if the argument doesn't start with dashes and
+    /// has the form `key=value`, e.g. `VAR=1`. Returns the key and the value.
+    /// The key must not be empty; the value may be empty.
+    pub fn parse_assignment(&mut self) -> Option<(&str, &str)> {
+        let arg = self.bump_if(|s, kind| {
+            kind == TokenKind::NoDash && s.find('=').is_some_and(|i| i > 0)
+        })?;
+        arg.split_once('=')
+    }
+
     /// Eat the current token if the argument doesn't start with dashes and
     /// matches `token` exactly.
-    pub fn eat_no_dash<'a>(&mut self, token: &'a str) -> Option<&str> {
+    pub fn eat_no_dash(&mut self, token: &str) -> Option<&str> {
         if let Some((s, TokenKind::NoDash)) = self.current() {
             if token == s {
                 return Some(self.bump(token.len()));
@@ -313,7 +853,15 @@ impl ArgsInput {
     ///
     /// Does not work if the token appears after an equals sign has already been
     /// parsed.
-    pub fn eat_one_dash<'a>(&mut self, token: &'a str) -> Option<&str> {
+    ///
+    /// If `token` is followed by a value separator (`=` by default), the
+    /// separator is skipped and the rest of the argument is a value
+    /// ([`TokenKind::AfterEquals`]), so `-o=value` can only be parsed as the
+    /// flag `o` with the value `value`. Otherwise, the rest of the argument
+    /// ([`TokenKind::AfterOneDash`]) can be parsed either as more short flags
+    /// or as a value, so `-ovalue` can be parsed as the flags `o`, `v`, `a`...
+    /// or as the flag `o` with the value `value`.
+    pub fn eat_one_dash(&mut self, token: &str) -> Option<&str> {
         if let Some((s, TokenKind::OneDash)) | Some((s, TokenKind::AfterOneDash)) =
             self.current()
         {
@@ -324,16 +872,23 @@ impl ArgsInput {
         None
     }
 
-    /// Eat the current token if the argument starts with (at least) two dashes,
-    /// and the current token either matches `token` exactly, or starts with
-    /// `token` followed by an equals sign.
+    /// Eat the current token if the argument starts with two dashes, and the
+    /// current token either matches `token` exactly, or starts with `token`
+    /// followed by an equals sign.
+    ///
+    /// Arguments starting with three or more dashes (e.g. `---foo`) are
+    /// considered malformed and are never matched. They can only be consumed
+    /// as a whole, e.g. with [`ArgsInput::bump_argument()`].
     ///
     /// Does not work if the token appears after an equals sign has already been
     /// parsed.
-    pub fn eat_two_dashes<'a>(&mut self, token: &'a str) -> Option<&str> {
+    pub fn eat_two_dashes(&mut self, token: &str) -> Option<&str> {
         if let Some((s, TokenKind::TwoDashes)) = self.current() {
+            if s.starts_with('-') {
+                return None;
+            }
             if let Some(rest) = s.strip_prefix(token) {
-                if rest.is_empty() || rest.starts_with('=') {
+                if rest.is_empty() || rest.starts_with(&self.value_separators[..]) {
                     return Some(self.bump(token.len()));
                 }
             }
@@ -345,7 +900,7 @@ impl ArgsInput {
     ///
     /// This method only works if the current [`TokenKind`] is either `NoDash`,
     /// `AfterOneDash` or `AfterEquals`.
-    pub fn eat_value<'a>(&mut self, token: &'a str) -> Option<&str> {
+    pub fn eat_value(&mut self, token: &str) -> Option<&str> {
         if let Some((s, kind)) = self.current() {
             match kind {
                 TokenKind::TwoDashes | TokenKind::OneDash => return None,
@@ -366,10 +921,7 @@ impl ArgsInput {
 
     /// Eat the current token (including any leading dashes) if it matches
     /// `token` exactly.
-    pub fn eat_value_allows_leading_dashes<'a>(
-        &mut self,
-        token: &'a str,
-    ) -> Option<&str> {
+    pub fn eat_value_allows_leading_dashes(&mut self, token: &str) -> Option<&str> {
         if let Some(s) = self.current_str_with_leading_dashes() {
             if let Some(rest) = s.strip_prefix(token) {
                 if rest.is_empty() {
@@ -404,14 +956,18 @@ impl ArgsInput {
         }
     }
 
-    /// If the argument starts with two (or more) dashes, returns a helper
-    /// struct for obtaining, validating and eating the next token.
+    /// If the argument starts with two dashes, returns a helper struct for
+    /// obtaining, validating and eating the next token. Like
+    /// [`ArgsInput::eat_two_dashes()`], this doesn't match arguments starting
+    /// with three or more dashes.
     pub fn two_dashes(&mut self) -> Option<InputPart<'_>>
     where
         Self: Sized,
     {
         match self.current() {
-            Some((s, TokenKind::TwoDashes)) => Some(InputPart::new(s.len(), self)),
+            Some((s, TokenKind::TwoDashes)) if !s.starts_with('-') => {
+                Some(InputPart::new(s.len(), self))
+            }
             _ => None,
         }
     }
@@ -440,9 +996,7 @@ impl ArgsInput {
     where
         Self: Sized,
     {
-        match self.current_str_with_leading_dashes() {
-            Some(s) => Some(InputPartLd::new(s.len(), self)),
-            None => None,
-        }
+        let len = self.current_str_with_leading_dashes()?.len();
+        Some(InputPartLd::new(len, self))
     }
 }
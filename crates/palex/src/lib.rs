@@ -9,7 +9,7 @@
 //!
 //! Check the `examples` folder for examples.
 
-pub use input::ArgsInput;
+pub use input::{ArgsInput, BoxedError};
 pub use token_kind::TokenKind;
 
 mod input;
@@ -1,6 +1,6 @@
 use std::vec::IntoIter;
 
-use crate::ArgsInput;
+use crate::{ArgsInput, TokenKind};
 
 fn input(s: &'static str) -> IntoIter<String> {
     let v: Vec<String> = s.split(' ').map(ToString::to_string).collect();
@@ -162,3 +162,422 @@ fn test_modes() {
         assert_eq!(input.eat_no_dash("c"), Some("c"));
     }
 }
+
+#[test]
+fn test_stash_unknown() {
+    let mut input = ArgsInput::new(input("--known x --unknown y -ab=c"));
+    input.set_collect_unknown(true);
+    assert_eq!(input.eat_two_dashes("known"), Some("known"));
+    assert_eq!(input.eat_value("x"), Some("x"));
+    assert_eq!(input.stash_unknown(), Some("--unknown"));
+    assert_eq!(input.stash_unknown(), Some("y"));
+    assert_eq!(input.eat_one_dash("a"), Some("a"));
+    assert_eq!(input.stash_unknown(), Some("-b=c"));
+    assert_eq!(input.stash_unknown(), None);
+    assert_eq!(input.take_unknown(), vec!["--unknown", "y", "-b=c"]);
+    assert!(input.take_unknown().is_empty());
+}
+
+#[test]
+fn test_stash_unknown_short_flag_group() {
+    let mut input = ArgsInput::new(input("-abc=d"));
+    assert_eq!(input.eat_one_dash("a"), Some("a"));
+    assert_eq!(input.stash_unknown(), Some("-bc=d"));
+
+    let mut input = ArgsInput::new(input.take_unknown().into_iter());
+    assert_eq!(input.eat_one_dash("b"), Some("b"));
+    assert_eq!(input.eat_one_dash("c"), Some("c"));
+    assert_eq!(input.eat_value("d"), Some("d"));
+    assert!(input.is_empty());
+}
+
+#[test]
+fn test_insert_args() {
+    let mut input = ArgsInput::new(input("a --b"));
+    assert_eq!(input.eat_no_dash("a"), Some("a"));
+    input.insert_args(vec!["--x".to_string(), "y".to_string()]);
+    assert_eq!(input.eat_two_dashes("b"), Some("b"));
+    assert_eq!(input.eat_two_dashes("x"), Some("x"));
+    assert_eq!(input.eat_no_dash("y"), Some("y"));
+    assert!(input.is_empty());
+
+    input.insert_args(vec!["-v".to_string()]);
+    assert_eq!(input.eat_one_dash("v"), Some("v"));
+    assert!(input.is_empty());
+}
+
+#[test]
+fn test_current_argument_raw() {
+    let mut input = ArgsInput::new(input("-abc --foo=bar baz"));
+    assert_eq!(input.current_argument_raw(), Some("-abc"));
+    assert_eq!(input.eat_one_dash("a"), Some("a"));
+    assert_eq!(input.current_argument_raw(), Some("-abc"));
+    assert_eq!(input.eat_one_dash("b"), Some("b"));
+    assert_eq!(input.eat_one_dash("c"), Some("c"));
+    assert_eq!(input.current_argument_raw(), Some("--foo=bar"));
+    assert_eq!(input.eat_two_dashes("foo"), Some("foo"));
+    assert_eq!(input.current_argument_raw(), Some("--foo=bar"));
+    assert_eq!(input.eat_value("bar"), Some("bar"));
+    assert_eq!(input.current_argument_raw(), Some("baz"));
+    assert_eq!(input.stash_unknown(), Some("baz"));
+    assert_eq!(input.current_argument_raw(), None);
+}
+
+#[test]
+#[cfg(unix)]
+fn test_eat_value_os() {
+    use std::ffi::OsString;
+    use std::os::unix::ffi::OsStringExt;
+
+    let raw = OsString::from_vec(vec![b'a', 0xff, b'b']);
+    let args = vec!["--path".into(), raw.clone(), "-x".into(), raw.clone(), "c".into()];
+    let mut input = ArgsInput::from_os_args(args);
+    assert_eq!(input.eat_value_os(), None);
+    assert_eq!(input.eat_two_dashes("path"), Some("path"));
+    assert_eq!(input.eat_value_os(), Some(raw.clone()));
+    assert_eq!(input.eat_one_dash("x"), Some("x"));
+
+    // the lossy conversion is used if the raw argument is not requested
+    assert_eq!(input.rest_as_string(), "a\u{fffd}b c");
+    assert_eq!(input.eat_value_os(), Some(raw));
+    assert_eq!(input.eat_value_os(), None);
+    assert_eq!(input.eat_no_dash("c"), Some("c"));
+    assert!(input.is_empty());
+}
+
+#[test]
+fn test_response_files() {
+    let path = std::env::temp_dir().join("palex_test_response_file.txt");
+    std::fs::write(&path, "--color always\n  -v\t@nested\n").unwrap();
+    let path = path.to_str().unwrap();
+
+    let args =
+        vec![format!("@{}", path), "x".into(), format!("@{}", path), "@missing".into()];
+    let mut input = ArgsInput::new(args.into_iter()).with_response_files(true);
+    assert_eq!(input.eat_two_dashes("color"), Some("color"));
+    assert_eq!(input.eat_value("always"), Some("always"));
+    assert_eq!(input.eat_one_dash("v"), Some("v"));
+    assert_eq!(input.eat_value("@nested"), Some("@nested"));
+    assert_eq!(input.eat_value("x"), Some("x"));
+    assert_eq!(input.eat_two_dashes("color"), Some("color"));
+    assert_eq!(input.eat_value("always"), Some("always"));
+    assert_eq!(input.eat_one_dash("v"), Some("v"));
+    assert_eq!(input.eat_value("@nested"), Some("@nested"));
+    assert_eq!(input.eat_value("@missing"), Some("@missing"));
+    assert!(input.is_empty());
+
+    let args = vec![format!("@{}", path)];
+    let mut input = ArgsInput::new(args.into_iter());
+    assert_eq!(input.eat_value(&format!("@{}", path)), Some(&*format!("@{}", path)));
+}
+
+#[test]
+fn test_three_dashes() {
+    let mut input = ArgsInput::new(input("---x ---=y --- --x"));
+    assert_eq!(input.eat_two_dashes("-x"), None);
+    assert_eq!(input.eat_two_dashes("x"), None);
+    assert_eq!(input.eat_two_dashes(""), None);
+    assert!(input.two_dashes().is_none());
+    assert_eq!(input.eat_one_dash("-"), None);
+    assert_eq!(input.bump_argument(), Some("-x"));
+    assert_eq!(input.eat_two_dashes(""), None);
+    assert_eq!(input.bump_argument(), Some("-=y"));
+    assert_eq!(input.eat_two_dashes(""), None);
+    assert_eq!(input.bump_argument(), Some("-"));
+    assert_eq!(input.eat_two_dashes("x"), Some("x"));
+    assert!(input.is_empty());
+}
+
+#[test]
+fn test_arg_index() {
+    let mut input = ArgsInput::new(input("-ab --c=d e"));
+    assert_eq!(input.arg_index(), 0);
+    assert_eq!(input.eat_one_dash("a"), Some("a"));
+    assert_eq!(input.arg_index(), 0);
+    assert_eq!(input.eat_value("b"), Some("b"));
+    assert_eq!(input.arg_index(), 1);
+    assert_eq!(input.eat_two_dashes("c"), Some("c"));
+    assert_eq!(input.eat_value("d"), Some("d"));
+    assert_eq!(input.arg_index(), 2);
+    assert_eq!(input.bump_argument(), Some("e"));
+    assert_eq!(input.arg_index(), 3);
+}
+
+#[test]
+fn test_rest_as_string() {
+    let args = vec!["--color", "-ab", "show", "pos 1", "", "a\"b"];
+    let mut input = ArgsInput::new(args.into_iter().map(ToString::to_string));
+    assert_eq!(input.eat_two_dashes("color"), Some("color"));
+    assert_eq!(input.eat_one_dash("a"), Some("a"));
+    assert_eq!(input.rest_as_string(), r#"-b show "pos 1" "" a"b"#);
+    assert_eq!(input.bump_argument(), Some("b"));
+    assert_eq!(input.rest_as_string(), r#"show "pos 1" "" a"b"#);
+    assert_eq!(input.eat_no_dash("show"), Some("show"));
+    assert_eq!(input.eat_no_dash("pos 1"), Some("pos 1"));
+    assert_eq!(input.eat_no_dash(""), Some(""));
+    assert_eq!(input.eat_no_dash("a\"b"), Some("a\"b"));
+    assert_eq!(input.rest_as_string(), "");
+
+    let mut input = ArgsInput::from("-abc foo");
+    assert_eq!(input.eat_one_dash("a"), Some("a"));
+    assert_eq!(input.rest_as_string(), "-bc foo");
+    assert_eq!(input.stash_unknown(), Some("-bc"));
+}
+
+#[test]
+fn test_into_remaining() {
+    let args = vec!["--color", "-ab", "show", "-- x"];
+    let mut input = ArgsInput::new(args.into_iter().map(ToString::to_string));
+    assert_eq!(input.eat_two_dashes("color"), Some("color"));
+    assert_eq!(input.eat_one_dash("a"), Some("a"));
+    assert_eq!(input.into_remaining(), vec!["-b", "show", "-- x"]);
+
+    let mut input = ArgsInput::from("-abc foo");
+    assert_eq!(input.eat_one_dash("a"), Some("a"));
+    assert_eq!(input.into_remaining(), vec!["-bc", "foo"]);
+
+    let mut input = ArgsInput::new(vec!["a".to_string()].into_iter());
+    assert_eq!(input.bump_argument(), Some("a"));
+    assert!(input.into_remaining().is_empty());
+}
+
+#[test]
+fn test_bump_if() {
+    let mut input = ArgsInput::new(input("-ab sub"));
+    assert_eq!(input.bump_if(|_, kind| kind == TokenKind::NoDash), None);
+    assert_eq!(input.bump_if(|s, _| s == "a"), None);
+    assert_eq!(
+        input.bump_if(|s, kind| kind == TokenKind::OneDash && s == "ab"),
+        Some("ab")
+    );
+    assert_eq!(input.bump_if(|s, _| s.starts_with("sub")), Some("sub"));
+    assert_eq!(input.bump_if(|_, _| true), None);
+}
+
+#[test]
+fn test_parse_assignment() {
+    let mut input = ArgsInput::new(input("VAR=1 X= =y target --a=b"));
+    assert_eq!(input.parse_assignment(), Some(("VAR", "1")));
+    assert_eq!(input.parse_assignment(), Some(("X", "")));
+    assert_eq!(input.parse_assignment(), None);
+    assert_eq!(input.bump_argument(), Some("=y"));
+    assert_eq!(input.parse_assignment(), None);
+    assert_eq!(input.bump_argument(), Some("target"));
+    assert_eq!(input.parse_assignment(), None);
+}
+
+#[test]
+fn test_next_is_flag() {
+    let mut input = ArgsInput::new(input("a -bc --d=e"));
+    assert!(!input.next_is_flag());
+    input.bump_argument();
+    assert!(input.next_is_flag());
+    input.bump(1);
+    assert!(input.next_is_flag());
+    input.bump(1);
+    assert!(input.next_is_flag());
+    input.bump(1);
+    assert!(!input.next_is_flag());
+}
+
+#[test]
+fn test_value_separator() {
+    let mut input = ArgsInput::new(input("--out:foo -o:bar --out=baz"));
+    input.set_value_separator(':');
+    assert_eq!(input.eat_two_dashes("out"), Some("out"));
+    assert_eq!(input.value().map(|v| v.eat()), Some("foo"));
+    assert_eq!(input.eat_one_dash("o"), Some("o"));
+    assert_eq!(input.value().map(|v| v.eat()), Some("bar"));
+    assert_eq!(input.eat_two_dashes("out"), None);
+    assert_eq!(input.eat_two_dashes("out=baz"), Some("out=baz"));
+}
+
+#[test]
+fn test_value_separators() {
+    let mut input = ArgsInput::new(input("--out=foo --out:bar -o:baz"));
+    input.set_value_separators(&['=', ':']);
+    assert_eq!(input.eat_two_dashes("out"), Some("out"));
+    assert_eq!(input.value().map(|v| v.eat()), Some("foo"));
+    assert_eq!(input.eat_two_dashes("out"), Some("out"));
+    assert_eq!(input.value().map(|v| v.eat()), Some("bar"));
+    assert_eq!(input.eat_one_dash("o"), Some("o"));
+    assert_eq!(input.value().map(|v| v.eat()), Some("baz"));
+    assert!(input.is_empty());
+}
+
+/// A small xorshift random number generator, so the fuzz test doesn't need
+/// any dependencies and is reproducible
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0 as usize
+    }
+
+    fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.next() % items.len()]
+    }
+}
+
+const PIECES: &[&str] = &["-", "--", "=", ":", "→", "a", "b", "é", "ab", ""];
+
+fn random_args(rng: &mut Rng) -> Vec<String> {
+    (0..rng.next() % 5)
+        .map(|_| (0..rng.next() % 5).map(|_| rng.pick(PIECES)).collect())
+        .collect()
+}
+
+/// Returns a random length that is a char boundary of `s`
+fn random_boundary(rng: &mut Rng, s: &str) -> usize {
+    let boundaries: Vec<usize> =
+        s.char_indices().map(|(i, _)| i).chain(Some(s.len())).collect();
+    boundaries[rng.next() % boundaries.len()]
+}
+
+fn random_op(rng: &mut Rng, input: &mut ArgsInput) {
+    let before = input.current_str_with_leading_dashes().map_or(0, str::len);
+    let token = rng.pick(PIECES);
+
+    let eaten = match rng.next() % 16 {
+        0 => input.eat_no_dash(token).map(str::len),
+        1 => input.eat_one_dash(token).map(str::len),
+        2 => input.eat_two_dashes(token).map(str::len),
+        3 => input.eat_value(token).map(str::len),
+        4 => input.eat_value_allows_leading_dashes(token).map(str::len),
+        5 => input.bump_argument().map(str::len),
+        6 => input.parse_assignment().map(|(k, v)| k.len() + 1 + v.len()),
+        7 => input.bump_if(|s, _| s.len() & 1 == 0).map(str::len),
+        8 => input.no_dash().and_then(|p| p.take_char()).map(|p| p.eat().len()),
+        9 => input.one_dash().and_then(|p| p.take_char()).map(|p| p.eat().len()),
+        10 => input.two_dashes().map(|p| p.take_until('=').eat().len()),
+        11 => input.value().map(|p| {
+            let len = random_boundary(rng, p.as_str());
+            p.take(len).eat().len()
+        }),
+        12 => input.value_allows_leading_dashes().map(|p| {
+            let len = random_boundary(rng, p.as_str());
+            p.take(len).eat().len()
+        }),
+        13 => input.value_allows_leading_dashes().map(|p| p.take_until('a').eat().len()),
+        14 => {
+            input.set_ignore_dashes(rng.next() & 1 == 0);
+            None
+        }
+        _ => {
+            let separators = [&['='][..], &[':'], &['→'], &['=', '→']];
+            input.set_value_separators(separators[rng.next() % separators.len()]);
+            None
+        }
+    };
+
+    if let Some(eaten) = eaten {
+        assert!(eaten <= before, "consumed {} bytes of a {} byte token", eaten, before);
+    }
+    let _ = (input.can_parse_value_no_whitespace(), input.can_parse_dash_argument());
+}
+
+#[test]
+fn test_random_inputs() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..5000 {
+        let args = random_args(&mut rng);
+        let mut input = ArgsInput::new(args.clone().into_iter());
+        for _ in 0..30 {
+            random_op(&mut rng, &mut input);
+        }
+        while input.bump_argument().is_some() {}
+        assert!(input.is_empty(), "input {:?} wasn't consumed", args);
+    }
+}
+
+#[test]
+fn test_multibyte_value_separator() {
+    let mut input = ArgsInput::new(input("--out→foo -o→bar"));
+    input.set_value_separator('→');
+    assert_eq!(input.eat_two_dashes("out"), Some("out"));
+    assert_eq!(input.value().map(|v| v.eat()), Some("foo"));
+    assert_eq!(input.eat_one_dash("o"), Some("o"));
+    assert_eq!(input.value().map(|v| v.eat()), Some("bar"));
+    assert!(input.is_empty());
+}
+
+#[test]
+fn test_one_dash_equals() {
+    let mut input = ArgsInput::new(input("-o=value -ovalue -ovalue -o= -ab=c"));
+    assert_eq!(input.eat_one_dash("o"), Some("o"));
+    assert_eq!(input.current(), Some(("value", TokenKind::AfterEquals)));
+    assert!(!input.can_parse_dash_argument());
+    assert_eq!(input.eat_one_dash("v"), None);
+    assert_eq!(input.value().map(|v| v.eat()), Some("value"));
+
+    // the rest can be a value...
+    assert_eq!(input.eat_one_dash("o"), Some("o"));
+    assert_eq!(input.current(), Some(("value", TokenKind::AfterOneDash)));
+    assert!(input.can_parse_value_no_whitespace());
+    assert_eq!(input.value().map(|v| v.eat()), Some("value"));
+
+    // ...or more short flags
+    assert_eq!(input.eat_one_dash("o"), Some("o"));
+    assert!(input.can_parse_dash_argument());
+    assert_eq!(input.eat_one_dash("v"), Some("v"));
+    assert_eq!(input.eat_one_dash("alue"), Some("alue"));
+
+    assert_eq!(input.eat_one_dash("o"), Some("o"));
+    assert_eq!(input.current(), Some(("", TokenKind::AfterEquals)));
+    assert_eq!(input.value().map(|v| v.eat()), Some(""));
+
+    assert_eq!(input.eat_one_dash("a"), Some("a"));
+    assert_eq!(input.eat_one_dash("b"), Some("b"));
+    assert_eq!(input.current(), Some(("c", TokenKind::AfterEquals)));
+    assert_eq!(input.value().map(|v| v.eat()), Some("c"));
+    assert!(input.is_empty());
+}
+
+#[test]
+fn test_peek_following_args() {
+    let mut input = ArgsInput::new(input("a --b c"));
+    assert_eq!(input.peek_following_args(1), ["--b"]);
+    assert_eq!(input.peek_following_args(5), ["--b", "c"]);
+    assert_eq!(input.eat_no_dash("a"), Some("a"));
+    assert_eq!(input.peek_following_args(2), ["c"]);
+    input.insert_args(vec!["x".to_string()]);
+    assert_eq!(input.peek_following_args(2), ["x", "c"]);
+    assert_eq!(input.eat_two_dashes("b"), Some("b"));
+    assert_eq!(input.eat_no_dash("x"), Some("x"));
+    assert_eq!(input.eat_no_dash("c"), Some("c"));
+    assert!(input.is_empty());
+    assert!(input.peek_following_args(1).is_empty());
+}
+
+#[test]
+fn test_peek_no_dash() {
+    let mut input = ArgsInput::new(input("show --x -y"));
+    assert_eq!(input.peek_no_dash(), Some("show"));
+    assert_eq!(input.peek_no_dash(), Some("show"));
+    input.bump_argument();
+    assert_eq!(input.peek_no_dash(), None);
+    input.bump_argument();
+    assert_eq!(input.peek_no_dash(), None);
+    input.set_ignore_dashes(true);
+    assert_eq!(input.peek_no_dash(), Some("-y"));
+}
+
+#[test]
+fn test_from_shell_str() {
+    let mut input = ArgsInput::from_shell_str(r#"--name "a b" -x"#);
+    assert_eq!(input.eat_two_dashes("name"), Some("name"));
+    assert_eq!(input.bump_argument(), Some("a b"));
+    assert_eq!(input.eat_one_dash("x"), Some("x"));
+    assert!(input.is_empty());
+
+    let words = |s| ArgsInput::from_shell_str(s).into_remaining();
+    assert_eq!(words("  a\t b  "), ["a", "b"]);
+    assert_eq!(words(r#"'a "b"' "c 'd'""#), [r#"a "b""#, "c 'd'"]);
+    assert_eq!(words(r#"a\ b "c\"d\e" 'f\g'"#), ["a b", r#"c"d\e"#, r"f\g"]);
+    assert_eq!(words(r#"--x="" '' a"b"c"#), ["--x=", "", "abc"]);
+    assert_eq!(words("'unterminated quote"), ["unterminated quote"]);
+    assert!(words("").is_empty());
+}
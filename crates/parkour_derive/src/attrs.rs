@@ -7,18 +7,53 @@ use crate::parse_attrs;
 pub enum Attr {
     Parkour(Parkour),
     Arg(Arg),
+    Relation(Relation),
+    /// `arg(multiple)`: the argument may be repeated
+    Multiple,
+    /// `arg(flatten)`: the field is a `parkour(group)` struct whose arguments
+    /// are parsed inline
+    Flatten,
+    /// `arg(value_delimiter = ';')`: the delimiter between the values of a list
+    /// or array field
+    ValueDelimiter(char),
+    /// `arg(global)`: the flag is also recognized after a subcommand
+    Global,
 }
 
 pub enum Parkour {
     Main,
     Default(Option<Box<Expr>>),
     Subcommand(Option<String>),
+    CatchAll,
+    AutoHelp(Option<Box<Expr>>),
+    DefaultSubcommand(String),
+    Skip,
+    Group,
+    /// `parkour(alias = "..")`: an additional string accepted for a variant
+    Alias(String),
+    /// `parkour(numeric)`: the zero-based index of a variant is accepted in
+    /// addition to its name
+    Numeric,
 }
 
 #[derive(PartialEq, Eq)]
 pub enum Arg {
-    Named { long: Vec<Option<String>>, short: Vec<Option<String>> },
-    Positional { name: Option<String> },
+    Named {
+        long: Vec<Option<String>>,
+        short: Vec<Option<String>>,
+    },
+    Positional {
+        name: Option<String>,
+    },
+    /// `arg(subcommand)`: the field is parsed as a subcommand. `arg(global)`
+    /// flags need this to know where the subcommand starts.
+    Subcommand,
+}
+
+/// A constraint between two fields, which is checked after parsing
+pub enum Relation {
+    ConflictsWith(String),
+    Requires(String),
 }
 
 pub fn parse(attrs: &[Attribute]) -> Result<Vec<(Attr, Span)>> {
@@ -29,7 +64,7 @@ pub fn parse(attrs: &[Attribute]) -> Result<Vec<(Attr, Span)>> {
             if *ident == "parkour" {
                 parse_parkour_attrs(&a.tokens, &mut result)?;
             } else if *ident == "arg" {
-                result.push((Attr::Arg(parse_arg_attrs(&a.tokens)?), ident.span()));
+                parse_arg_attrs(&a.tokens, ident.span(), &mut result)?;
             }
         }
     }
@@ -54,6 +89,35 @@ fn parse_parkour_attrs(tokens: &TokenStream, buf: &mut Vec<(Attr, Span)>) -> Res
             ("default", Some(t)) => {
                 buf.push((Attr::Parkour(Parkour::Default(Some(Box::new(t)))), id.span()));
             }
+            ("numeric", None) => {
+                buf.push((Attr::Parkour(Parkour::Numeric), id.span()));
+            }
+            ("group", None) => {
+                buf.push((Attr::Parkour(Parkour::Group), id.span()));
+            }
+            ("skip", None) => {
+                buf.push((Attr::Parkour(Parkour::Skip), id.span()));
+            }
+            ("catch_all", None) => {
+                buf.push((Attr::Parkour(Parkour::CatchAll), id.span()));
+            }
+            ("auto_help", Some(t)) => {
+                buf.push((
+                    Attr::Parkour(Parkour::AutoHelp(Some(Box::new(t)))),
+                    id.span(),
+                ));
+            }
+            ("auto_help", None) => {
+                buf.push((Attr::Parkour(Parkour::AutoHelp(None)), id.span()));
+            }
+            ("default_subcommand", Some(t)) => {
+                let s = parse_string(&t)?;
+                buf.push((Attr::Parkour(Parkour::DefaultSubcommand(s)), id.span()));
+            }
+            ("alias", Some(t)) => {
+                let s = parse_string(&t)?;
+                buf.push((Attr::Parkour(Parkour::Alias(s)), id.span()));
+            }
             ("default", None) => {
                 buf.push((Attr::Parkour(Parkour::Default(None)), id.span()));
             }
@@ -63,10 +127,16 @@ fn parse_parkour_attrs(tokens: &TokenStream, buf: &mut Vec<(Attr, Span)>) -> Res
     Ok(())
 }
 
-fn parse_arg_attrs(tokens: &TokenStream) -> Result<Arg> {
+fn parse_arg_attrs(
+    tokens: &TokenStream,
+    attr_span: Span,
+    buf: &mut Vec<(Attr, Span)>,
+) -> Result<()> {
     let mut long = Vec::new();
     let mut short = Vec::new();
     let mut positional = None;
+    let mut subcommand = false;
+    let mut has_modifiers = false;
 
     let span = tokens.span();
     let values = parse_attrs::parse(tokens)?;
@@ -92,6 +162,36 @@ fn parse_arg_attrs(tokens: &TokenStream) -> Result<Arg> {
                 err_on_duplicate(positional.is_some(), id.span())?;
                 positional = Some(Some(parse_string(&p)?));
             }
+            ("subcommand", None) => {
+                err_on_duplicate(subcommand, id.span())?;
+                subcommand = true;
+            }
+            ("global", None) => {
+                buf.push((Attr::Global, id.span()));
+                has_modifiers = true;
+            }
+            ("conflicts_with", Some(t)) => {
+                let other = parse_string(&t)?;
+                buf.push((Attr::Relation(Relation::ConflictsWith(other)), id.span()));
+                has_modifiers = true;
+            }
+            ("flatten", None) => {
+                buf.push((Attr::Flatten, id.span()));
+                has_modifiers = true;
+            }
+            ("value_delimiter", Some(t)) => {
+                buf.push((Attr::ValueDelimiter(parse_char(&t)?), id.span()));
+                has_modifiers = true;
+            }
+            ("multiple", None) => {
+                buf.push((Attr::Multiple, id.span()));
+                has_modifiers = true;
+            }
+            ("requires", Some(t)) => {
+                let other = parse_string(&t)?;
+                buf.push((Attr::Relation(Relation::Requires(other)), id.span()));
+                has_modifiers = true;
+            }
             (s, _) => bail!(id.span(), "unexpected key {:?}", s),
         }
     }
@@ -102,11 +202,25 @@ fn parse_arg_attrs(tokens: &TokenStream) -> Result<Arg> {
             "`arg(positional)` can't be used together with `arg(long)` or `arg(short)`",
         );
     }
-    if let Some(name) = positional {
-        Ok(Arg::Positional { name })
-    } else {
-        Ok(Arg::Named { long, short })
+    if subcommand && (positional.is_some() || !(long.is_empty() && short.is_empty())) {
+        bail!(
+            span,
+            "`arg(subcommand)` can't be used together with `arg(long)`, `arg(short)` or \
+             `arg(positional)`",
+        );
     }
+    let arg = if subcommand {
+        Arg::Subcommand
+    } else if let Some(name) = positional {
+        Arg::Positional { name }
+    } else if long.is_empty() && short.is_empty() && has_modifiers {
+        // e.g. `#[arg(multiple)]` in addition to `#[arg(long)]`
+        return Ok(());
+    } else {
+        Arg::Named { long, short }
+    };
+    buf.push((Attr::Arg(arg), attr_span));
+    Ok(())
 }
 
 fn parse_string(t: &Expr) -> Result<String> {
@@ -116,6 +230,13 @@ fn parse_string(t: &Expr) -> Result<String> {
     }
 }
 
+fn parse_char(t: &Expr) -> Result<char> {
+    match t {
+        Expr::Lit(ExprLit { lit: Lit::Char(c), .. }) => Ok(c.value()),
+        _ => bail!(t.span(), "invalid token: expected char literal"),
+    }
+}
+
 fn err_on_duplicate(b: bool, span: Span) -> Result<()> {
     if b {
         bail!(span, "key exists multiple times");
@@ -1,18 +1,25 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::spanned::Spanned;
-use syn::{Attribute, DataEnum, Ident, Result, Variant};
+use syn::{Attribute, DataEnum, Fields, Ident, Result, Variant};
 
+use super::structs;
 use crate::attrs::{Attr, Parkour};
 use crate::{attrs, utils};
 
 pub fn enums(name: &Ident, e: DataEnum, attrs: Vec<Attribute>) -> Result<TokenStream> {
-    let variants: Vec<Variant> = e.variants.into_iter().collect();
+    // variants with multiple named fields are parsed like a struct with the
+    // `parkour(subcommand)` attribute
+    let (struct_variants, variants): (Vec<Variant>, Vec<Variant>) =
+        e.variants.into_iter().partition(|v| {
+            matches!(v.fields, Fields::Named(_)) && utils::field_len(&v.fields) > 1
+        });
 
     if let Some(v) = variants.iter().find(|&v| utils::field_len(&v.fields) > 1) {
         bail!(
             v.fields.span(),
-            "The FromInput derive macro doesn't support variants with more than 1 field",
+            "The FromInput derive macro doesn't support tuple variants with more than 1 \
+             field",
         )
     }
 
@@ -23,12 +30,63 @@ pub fn enums(name: &Ident, e: DataEnum, attrs: Vec<Attribute>) -> Result<TokenSt
     let attrs = attrs::parse(&attrs)?;
     let is_main = attrs.iter().any(|(a, _)| matches!(a, Attr::Parkour(Parkour::Main)));
 
+    let struct_variant_idents: Vec<&Ident> =
+        struct_variants.iter().map(|v| &v.ident).collect();
+    let struct_variant_strs = utils::get_lowercase_ident_strs(&struct_variant_idents);
+    let struct_variant_bodies = struct_variants
+        .iter()
+        .zip(&struct_variant_strs)
+        .map(|(v, command)| {
+            let ident = &v.ident;
+            structs::fields_body(
+                &v.fields,
+                quote! { #name::#ident },
+                &attrs,
+                Some(command),
+            )
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let default_subcommand = attrs.iter().find_map(|(a, span)| match a {
+        Attr::Parkour(Parkour::DefaultSubcommand(s)) => Some((s, *span)),
+        _ => None,
+    });
+    let default_variant = match default_subcommand {
+        Some((command, span)) => {
+            if let Some(i) = empty_ident_strs.iter().position(|s| s == command) {
+                let ident = empty_idents[i];
+                quote! {
+                    input.expect_empty()?;
+                    Ok(#name::#ident {})
+                }
+            } else if let Some(i) = struct_variant_strs.iter().position(|s| s == command)
+            {
+                let body = &struct_variant_bodies[i];
+                quote! { #body }
+            } else {
+                bail!(span, "there is no subcommand named {:?}", command)
+            }
+        }
+        None => quote! { Err(parkour::Error::no_value()) },
+    };
+
     let start_bump = if is_main {
         quote! { input.bump_argument().unwrap(); }
     } else {
         quote! {}
     };
 
+    let mut help_text = String::new();
+    if !empty_ident_strs.is_empty() || !struct_variant_strs.is_empty() {
+        help_text.push_str("Commands:\n");
+        for command in empty_ident_strs.iter().chain(&struct_variant_strs) {
+            help_text.push_str(&format!("  {}\n", command));
+        }
+        help_text.push('\n');
+    }
+    help_text.push_str("Options:\n  -h, --help");
+    let auto_help = utils::generate_auto_help(&attrs, quote! { #help_text })?;
+
     let gen = quote! {
         #[automatically_derived]
         impl parkour::FromInput<'static> for #name {
@@ -42,15 +100,21 @@ pub fn enums(name: &Ident, e: DataEnum, attrs: Vec<Attribute>) -> Result<TokenSt
                 if input.parse_long_flag("") {
                     input.set_ignore_dashes(true);
                 }
+                #auto_help
 
                 #(
                     if input.parse_command(#empty_ident_strs) {
-                        // TODO: Parse -h and --help by default
                         input.expect_empty()?;
                         return Ok(#name::#empty_idents {});
                     }
                 )*
 
+                #(
+                    if input.parse_command(#struct_variant_strs) {
+                        return { #struct_variant_bodies };
+                    }
+                )*
+
                 #(
                     match <#inner_types as parkour::FromInput>::from_input(input, &Default::default()) {
                         Ok(__v) => return Ok( #name::#inner_type_ctors ),
@@ -60,7 +124,8 @@ pub fn enums(name: &Ident, e: DataEnum, attrs: Vec<Attribute>) -> Result<TokenSt
                         },
                     }
                 )*
-                Err(parkour::Error::no_value())
+
+                #default_variant
             }
         }
     };
@@ -1,10 +1,11 @@
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::{
-    Attribute, Fields, GenericArgument, Ident, PathArguments, Result, Type, TypePath,
+    Attribute, Fields, GenericArgument, Ident, Index, PathArguments, Result, Type,
+    TypePath,
 };
 
-use crate::attrs::{Arg, Attr, Parkour};
+use crate::attrs::{Arg, Attr, Parkour, Relation};
 use crate::{attrs, utils};
 
 pub fn structs(
@@ -17,6 +18,24 @@ pub fn structs(
     let subcommands = get_subcommand_names(&attrs, name)?;
 
     let is_main = attrs.iter().any(|(a, _)| matches!(a, Attr::Parkour(Parkour::Main)));
+    let is_group = attrs.iter().any(|(a, _)| matches!(a, Attr::Parkour(Parkour::Group)));
+    if is_group {
+        if is_main || !subcommands.is_empty() {
+            bail!(
+                Span::call_site(),
+                "`parkour(group)` can't be combined with `parkour(main)` or \
+                 `parkour(subcommand)`",
+            );
+        }
+        if let Fields::Unnamed(_) = s.fields {
+            bail!(
+                Span::call_site(),
+                "The FromInput derive macro doesn't support tuple structs",
+            );
+        }
+        return group(name, &s.fields);
+    }
+
     if is_main && !subcommands.is_empty() {
         bail!(
             Span::call_site(),
@@ -25,8 +44,8 @@ pub fn structs(
     } else if !is_main && subcommands.is_empty() {
         bail!(
             Span::call_site(),
-            "The FromInput derive macro requires a `parkour(main)` or \
-             `parkour(subcommand)` attribute",
+            "The FromInput derive macro requires a `parkour(main)`, \
+             `parkour(subcommand)` or `parkour(group)` attribute",
         );
     }
 
@@ -47,23 +66,189 @@ pub fn structs(
         }
     }
 
-    let mut field_idents = Vec::new();
-    let mut field_initials = Vec::new();
-    let mut field_getters = Vec::new();
-    let mut contexts = Vec::new();
+    let usage_name = if is_main { None } else { Some(subcommands[0].as_str()) };
+    let body = fields_body(&s.fields, quote! { #name }, &attrs, usage_name)?;
+
+    let gen = quote! {
+        #[automatically_derived]
+        impl parkour::FromInput<'static> for #name {
+            type Context = ();
+
+            fn from_input(input: &mut parkour::ArgsInput, _: &Self::Context)
+                    -> parkour::Result<Self>
+            {
+                if #main_condition {
+                    #body
+                } else {
+                    Err(parkour::Error::no_value())
+                }
+            }
+        }
+    };
+    Ok(gen)
+}
+
+/// The generated code for the fields of a struct (or of an enum variant)
+struct FieldsCode<'a> {
+    idents: Vec<&'a Ident>,
+    initials: Vec<TokenStream>,
+    /// The types of the variables that store the fields while parsing
+    state_types: Vec<TokenStream>,
+    /// The expressions that produce the final field values
+    values: Vec<TokenStream>,
+    /// The arguments, with the index of the field they belong to
+    args: Vec<(usize, ArgCode)>,
+    skipped: Vec<&'a Ident>,
+    relation_checks: TokenStream,
+    /// Statements that add the entries of the "Options" section of the help
+    /// message to a `Vec<&'static str>` named `options`
+    help_options: Vec<TokenStream>,
+    help_positionals: String,
+}
+
+enum ArgCode {
+    Action {
+        action: TokenStream,
+        context: TokenStream,
+        end: TokenStream,
+        /// `arg(global)`: the flag is also recognized after the subcommand
+        global: bool,
+        /// `arg(subcommand)`
+        subcommand: bool,
+    },
+    Flatten {
+        ty: TokenStream,
+    },
+}
+
+impl FieldsCode<'_> {
+    /// Returns the expressions that try to parse each argument. `places` are
+    /// the places where the fields are stored while parsing.
+    fn apply_exprs(&self, places: &[TokenStream]) -> Vec<TokenStream> {
+        self.args
+            .iter()
+            .map(|(i, arg)| {
+                let place = &places[*i];
+                match arg {
+                    ArgCode::Action { action, context, end, .. } => quote! {
+                        #action(&mut #place)
+                            .apply(input, &#context)
+                            .and_then(|applied| {
+                                if applied {
+                                    #end
+                                }
+                                Ok(applied)
+                            })
+                    },
+                    ArgCode::Flatten { ty } => quote! {
+                        <#ty as parkour::ArgGroup>::apply_arg(&mut #place, input)
+                    },
+                }
+            })
+            .collect()
+    }
+
+    fn has_global(&self) -> bool {
+        self.args.iter().any(|(_, a)| matches!(a, ArgCode::Action { global: true, .. }))
+    }
+
+    fn has_subcommand(&self) -> bool {
+        self.args
+            .iter()
+            .any(|(_, a)| matches!(a, ArgCode::Action { subcommand: true, .. }))
+    }
+
+    fn ctor(&self, ctor: TokenStream) -> TokenStream {
+        let idents = &self.idents;
+        let values = &self.values;
+        let skipped = &self.skipped;
+        quote! {
+            #ctor {
+                #(
+                    #idents: #values,
+                )*
+                #(
+                    #skipped: Default::default(),
+                )*
+            }
+        }
+    }
+}
+
+fn generate_fields(fields: &Fields) -> Result<FieldsCode<'_>> {
+    let mut code = FieldsCode {
+        idents: Vec::new(),
+        initials: Vec::new(),
+        state_types: Vec::new(),
+        values: Vec::new(),
+        args: Vec::new(),
+        skipped: Vec::new(),
+        relation_checks: TokenStream::new(),
+        help_options: Vec::new(),
+        help_positionals: String::new(),
+    };
+    let mut field_strs = Vec::new();
+    let mut relations = Vec::new();
 
-    for field in &s.fields {
+    for field in fields {
         let attrs = attrs::parse(&field.attrs)?;
         let ident = field.ident.as_ref().expect("a field has no ident");
 
+        // skipped fields aren't parsed, they're initialized with `Default`
+        if let Some((_, span)) =
+            attrs.iter().find(|(a, _)| matches!(a, Attr::Parkour(Parkour::Skip)))
+        {
+            if attrs.len() > 1 {
+                bail!(*span, "`parkour(skip)` can't be combined with other attributes");
+            }
+            code.skipped.push(ident);
+            continue;
+        }
+
+        // flattened fields are parsed with the `ArgGroup` trait
+        if let Some((_, span)) = attrs.iter().find(|(a, _)| matches!(a, Attr::Flatten)) {
+            if attrs.len() > 1 {
+                bail!(*span, "`arg(flatten)` can't be combined with other attributes");
+            }
+            let ty = &field.ty;
+            let ty = quote! { #ty };
+            code.args.push((code.idents.len(), ArgCode::Flatten { ty: ty.clone() }));
+            code.help_options.push(quote! {
+                options.extend(<#ty as parkour::ArgGroup>::help_options());
+            });
+            code.idents.push(ident);
+            code.initials.push(quote! { <#ty as parkour::ArgGroup>::init() });
+            code.state_types.push(quote! { <#ty as parkour::ArgGroup>::State });
+            code.values.push(quote! { <#ty as parkour::ArgGroup>::finish(#ident)? });
+            continue;
+        }
+
         let ty = parse_my_type(&field.ty);
 
+        let delimiter = attrs.iter().find_map(|(a, span)| match a {
+            Attr::ValueDelimiter(c) => Some((*c, *span)),
+            _ => None,
+        });
+        if let Some((_, span)) = delimiter {
+            if !is_list(&ty) && !is_array(&ty) {
+                bail!(
+                    span,
+                    "`arg(value_delimiter)` is only allowed for lists and arrays"
+                );
+            }
+        }
+        let delimiter = delimiter.map(|(c, _)| c);
+
         let mut field_str = None;
+        let mut multiple = false;
+        let mut global = None;
+        let mut subcommand = false;
+        let mut first_flag = None;
 
-        let mut args = Vec::new();
+        let mut contexts = Vec::new();
         for (attr, span) in attrs {
             if let Attr::Arg(a) = attr {
-                args.push(match a {
+                contexts.push(match a {
                     Arg::Named { long, short } => {
                         if long.is_empty() && short.is_empty() {
                             bail!(span, "no flags specified");
@@ -80,99 +265,378 @@ pub fn structs(
 
                         let (long, short) =
                             flatten_flags(span, &main_flag, &long, &short)?;
-                        generate_flag_context(&long, &short)
+                        let flag = generate_flag(&long, &short);
+                        if first_flag.is_none() {
+                            first_flag = Some(flag.clone());
+                        }
+
+                        let aliases: Vec<String> = long
+                            .iter()
+                            .map(|l| format!("--{}", l))
+                            .chain(short.iter().map(|s| format!("-{}", s)))
+                            .collect();
+                        let option = format!("  {}", aliases.join(", "));
+                        code.help_options.push(quote! { options.push(#option); });
+
+                        if is_array(&ty) {
+                            let delimiter = match delimiter {
+                                Some(c) => quote! { delimiter: Some(#c), },
+                                None => quote! {},
+                            };
+                            quote! {
+                                parkour::util::ArgCtx::new(#flag, parkour::impls::ArrayCtx {
+                                    greedy: true,
+                                    #delimiter
+                                    ..Default::default()
+                                })
+                            }
+                        } else if let MyType::Bool = ty {
+                            flag
+                        } else if let Some(c) = delimiter {
+                            quote! {
+                                parkour::impls::ListCtx {
+                                    separator: parkour::impls::Separator::Custom(#c),
+                                    ..#flag.into()
+                                }
+                            }
+                        } else {
+                            quote! { #flag.into() }
+                        }
                     }
 
                     Arg::Positional { name: None } => {
                         if field_str.is_none() {
                             field_str = Some(ident.to_string());
                         }
+                        code.help_positionals.push_str(&format!(" <{}>", ident));
 
                         quote! { todo!() }
                     }
-                    Arg::Positional { name: Some(_p) } => {
+                    Arg::Positional { name: Some(p) } => {
                         if field_str.is_none() {
                             field_str = Some(ident.to_string());
                         }
+                        code.help_positionals.push_str(&format!(" <{}>", p));
 
                         quote! { todo!() }
                     }
+                    Arg::Subcommand => {
+                        if subcommand {
+                            bail!(span, "`arg(subcommand)` is specified twice");
+                        }
+                        if let MyType::Bool | MyType::List = ty {
+                            bail!(span, "a subcommand field must not be a bool or a list");
+                        }
+                        subcommand = true;
+                        if field_str.is_none() {
+                            field_str = Some(ident.to_string());
+                        }
+                        code.help_positionals.push_str(&format!(" <{}>", ident));
+
+                        quote! { Default::default() }
+                    }
                 })
+            } else if let Attr::Multiple = attr {
+                multiple = true;
+            } else if let Attr::Global = attr {
+                global = Some(span);
+            } else if let Attr::Relation(r) = attr {
+                relations.push((ident, r, span));
             } else if let Attr::Parkour(_) = attr {
                 bail!(span, "this key is not yet implemented!");
             }
         }
 
-        if args.is_empty() {
+        if contexts.is_empty() {
             bail!(ident.span(), "This field is missing a `arg` attribute");
         }
-        contexts.push(args);
+        if let Some(span) = global {
+            if subcommand || first_flag.is_none() {
+                bail!(span, "`arg(global)` is only allowed for flags");
+            }
+        }
+        if subcommand && contexts.len() > 1 {
+            bail!(ident.span(), "a subcommand field can't have other `arg` attributes");
+        }
+        if subcommand && code.has_subcommand() {
+            bail!(
+                ident.span(),
+                "only one field can have the `arg(subcommand)` attribute"
+            );
+        }
 
-        field_idents.push(ident);
+        // after a bool flag, the rest of the argument may contain more short
+        // flags, e.g. `-vx`
+        let expect_end = match first_flag {
+            Some(flag) => quote! { input.expect_end_of_argument_for(&#flag)?; },
+            None => quote! { input.expect_end_of_argument()?; },
+        };
+        let end = match ty {
+            _ if subcommand => quote! {},
+            MyType::Bool => quote! {
+                if !input.can_parse_dash_argument() {
+                    #expect_end
+                }
+            },
+            _ => expect_end,
+        };
+
+        let action = match ty {
+            _ if subcommand => quote! { parkour::actions::SetSubcommand },
+            MyType::List => quote! { parkour::actions::Append },
+            MyType::Option(_) if is_list(&ty) => quote! { parkour::actions::Append },
+            _ if multiple => quote! { parkour::actions::Set },
+            _ => quote! { parkour::actions::SetOnce },
+        };
+
+        for context in contexts {
+            code.args.push((
+                code.idents.len(),
+                ArgCode::Action {
+                    action: action.clone(),
+                    context,
+                    end: end.clone(),
+                    global: global.is_some(),
+                    subcommand,
+                },
+            ));
+        }
+        code.idents.push(ident);
 
-        field_initials.push(match ty {
+        code.initials.push(match ty {
             MyType::Bool => quote! { false },
+            MyType::List => quote! { Default::default() },
             _ => quote! { None },
         });
+        code.state_types.push(match ty {
+            MyType::Bool => quote! { bool },
+            MyType::List | MyType::Option(_) => {
+                let ty = &field.ty;
+                quote! { #ty }
+            }
+            MyType::Other(t) => quote! { Option<#t> },
+        });
 
         let field_str = field_str.expect("a field has no string");
-        field_getters.push(match ty {
-            MyType::Bool | MyType::Option(_) => quote! {},
+        let is_set = match ty {
+            MyType::Bool => quote! { #ident },
+            MyType::List => quote! { !#ident.is_empty() },
+            _ => quote! { #ident.is_some() },
+        };
+        field_strs.push((ident, field_str.clone(), is_set));
+
+        code.values.push(match ty {
+            MyType::Bool | MyType::Option(_) | MyType::List => quote! { #ident },
             MyType::Other(_) => quote! {
-                .ok_or_else(|| {
+                #ident.ok_or_else(|| {
                     parkour::Error::missing_argument(#field_str)
                 })?
             },
         });
     }
 
-    let gen = quote! {
-        #[automatically_derived]
-        impl parkour::FromInput<'static> for #name {
-            type Context = ();
+    code.relation_checks = generate_relation_checks(&field_strs, &relations)?;
+    Ok(code)
+}
 
-            fn from_input(input: &mut parkour::ArgsInput, _: &Self::Context)
-                    -> parkour::Result<Self>
-            {
-                if #main_condition {
-                    #(
-                        let mut #field_idents = #field_initials;
-                    )*
-                    while input.is_not_empty() {
-                        if input.parse_long_flag("") {
-                            input.set_ignore_dashes(true);
+/// Generates the code that parses the fields of a struct (or of an enum
+/// variant) until the input is empty, and returns `Ok(#ctor { .. })`.
+/// `usage_name` is the name shown in the generated help message; if it is
+/// `None`, the name of the package is used.
+pub fn fields_body(
+    fields: &Fields,
+    ctor: TokenStream,
+    attrs: &[(Attr, Span)],
+    usage_name: Option<&str>,
+) -> Result<TokenStream> {
+    let code = generate_fields(fields)?;
+
+    let help_body = format!(" [OPTIONS]{}\n\nOptions:\n", code.help_positionals);
+    let usage = match usage_name {
+        None => quote! { concat!("Usage: ", env!("CARGO_PKG_NAME"), #help_body) },
+        Some(name) => {
+            let usage = format!("Usage: {}{}", name, help_body);
+            quote! { #usage }
+        }
+    };
+    // the options of flattened groups are only known at runtime
+    let help_options = &code.help_options;
+    let help_text = quote! {{
+        let mut options: Vec<&'static str> = Vec::new();
+        #( #help_options )*
+        options.push("  -h, --help");
+        format!("{}{}", #usage, options.join("\n"))
+    }};
+    let auto_help = utils::generate_auto_help(attrs, help_text)?;
+
+    let places: Vec<TokenStream> = code.idents.iter().map(|i| quote! { #i }).collect();
+    let mut apply_exprs = code.apply_exprs(&places);
+
+    // Arguments after the subcommand that the subcommand doesn't recognize are
+    // collected and parsed again here, so global flags work before and after
+    // the subcommand. All other arguments are only allowed before it. The
+    // arguments the caller stashed before are set aside meanwhile, and errors
+    // in the arguments after the subcommand get the subcommand as context.
+    let (after_subcommand, add_context) = if code.has_global() && code.has_subcommand() {
+        for ((_, arg), expr) in code.args.iter().zip(&mut apply_exprs) {
+            *expr = match arg {
+                ArgCode::Action { subcommand: true, .. } => quote! {{
+                    let name = input.peek_no_dash().map(str::to_string);
+                    let stashed = input.take_unknown();
+                    let collect_unknown = input.collect_unknown();
+                    input.set_collect_unknown(true);
+                    let applied = #expr;
+                    input.set_collect_unknown(collect_unknown);
+                    let unknown = input.take_unknown();
+                    input.insert_args(unknown);
+                    input.extend_unknown(stashed);
+                    if let Ok(true) = applied {
+                        after_subcommand = Some(name.unwrap_or_default());
+                    }
+                    applied
+                }},
+                ArgCode::Action { global: true, .. } => expr.clone(),
+                _ => quote! {
+                    if after_subcommand.is_some() { Ok(false) } else { #expr }
+                },
+            };
+        }
+        (
+            quote! { let mut after_subcommand: Option<String> = None; },
+            quote! {
+                let e = match &after_subcommand {
+                    Some(name) => e.chain(parkour::ErrorInner::InSubcommand(name.clone())),
+                    None => e,
+                };
+            },
+        )
+    } else {
+        (quote! {}, quote! {})
+    };
+    let field_idents = &code.idents;
+    let field_initials = &code.initials;
+    let relation_checks = &code.relation_checks;
+    let ctor = code.ctor(ctor);
+
+    Ok(quote! {
+        #(
+            let mut #field_idents = #field_initials;
+        )*
+        #after_subcommand
+        let error_count = input.stored_error_count();
+        while input.is_not_empty() {
+            if input.parse_long_flag("") {
+                input.set_ignore_dashes(true);
+            }
+            #auto_help
+
+            let arg_index = input.arg_index();
+            #(
+                let applied = #apply_exprs;
+                match applied {
+                    Ok(true) => continue,
+                    Ok(false) => {}
+                    Err(e) => {
+                        #add_context
+                        if !input.collect_all_errors() {
+                            return Err(e);
+                        }
+                        let is_value_error = e.is_value_error();
+                        input.record_error(e)?;
+                        // skip the invalid value, e.g. `x` in
+                        // `--num=x` or `--num x`. Other errors, like a
+                        // repeated flag, didn't leave a value behind
+                        if is_value_error
+                            && (input.can_parse_value_no_whitespace()
+                                || (input.arg_index() == arg_index + 1
+                                    && input.no_dash().is_some()))
+                        {
+                            input.bump_argument();
                         }
+                        continue;
+                    }
+                }
+            )*
 
-                        #(
-                            #(
-                                if parkour::actions::SetOnce(&mut #field_idents)
-                                    .apply(input, &#contexts)?
-                                {
-                                    input.expect_end_of_argument()?;
-                                    continue;
-                                }
-                            )*
-                        )*
+            if let Err(e) = input.expect_empty() {
+                #add_context
+                input.record_error(e)?;
+            }
+        }
+        // only report the errors of this invocation, not the ones that were
+        // stored before
+        let error_count = input.stored_error_count() - error_count;
+        if error_count > 0 {
+            return Err(parkour::ErrorInner::MultipleErrors(error_count).into());
+        }
+        #relation_checks
+        Ok(#ctor)
+    })
+}
+
+/// Generates the `ArgGroup` implementation for a `parkour(group)` struct
+fn group(name: &Ident, fields: &Fields) -> Result<TokenStream> {
+    let code = generate_fields(fields)?;
+    if code.has_global() || code.has_subcommand() {
+        bail!(
+            Span::call_site(),
+            "`arg(global)` and `arg(subcommand)` aren't supported in a `parkour(group)`",
+        );
+    }
+
+    let places: Vec<TokenStream> = (0..code.idents.len())
+        .map(|i| {
+            let i = Index::from(i);
+            quote! { state.#i }
+        })
+        .collect();
+    let apply_exprs = code.apply_exprs(&places);
+    let field_idents = &code.idents;
+    let field_initials = &code.initials;
+    let state_types = &code.state_types;
+    let relation_checks = &code.relation_checks;
+    let help_options = &code.help_options;
+    let ctor = code.ctor(quote! { #name });
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl parkour::ArgGroup for #name {
+            type State = ( #( #state_types, )* );
+
+            fn init() -> Self::State {
+                ( #( #field_initials, )* )
+            }
 
-                        input.expect_empty()?;
+            fn apply_arg(state: &mut Self::State, input: &mut parkour::ArgsInput)
+                    -> parkour::Result<bool>
+            {
+                #(
+                    if #apply_exprs? {
+                        return Ok(true);
                     }
-                    Ok(#name {
-                        #(
-                            #field_idents: #field_idents #field_getters,
-                        )*
-                    })
-                } else {
-                    Err(parkour::Error::no_value())
-                }
+                )*
+                Ok(false)
+            }
+
+            fn finish(state: Self::State) -> parkour::Result<Self> {
+                let ( #( #field_idents, )* ) = state;
+                #relation_checks
+                Ok(#ctor)
+            }
+
+            fn help_options() -> Vec<&'static str> {
+                let mut options = Vec::new();
+                #( #help_options )*
+                options
             }
         }
-    };
-    Ok(gen)
+    })
 }
 
 enum MyType<'a> {
     Bool,
     Option(&'a Type),
+    List,
     Other(&'a Type),
 }
 
@@ -187,7 +651,7 @@ fn is_bool(path: &TypePath) -> bool {
 
 fn parse_my_type(ty: &Type) -> MyType<'_> {
     if let Type::Path(path) = ty {
-        if is_bool(&path) {
+        if is_bool(path) {
             return MyType::Bool;
         } else if path.qself.is_none() {
             let segments = path.path.segments.iter().collect::<Vec<_>>();
@@ -209,31 +673,74 @@ fn parse_my_type(ty: &Type) -> MyType<'_> {
                     }
                 }
             }
+
+            if let Some(last) = segments.last() {
+                let is_list = ["Vec", "VecDeque", "LinkedList", "BTreeSet", "HashSet"]
+                    .iter()
+                    .any(|&list| last.ident == list);
+
+                if is_list {
+                    if let PathArguments::AngleBracketed(a) = &last.arguments {
+                        if let Some(GenericArgument::Type(_)) = a.args.iter().next() {
+                            return MyType::List;
+                        }
+                    }
+                }
+
+                // `Box<[T]>` and `Rc<[T]>`
+                if last.ident == "Box" || last.ident == "Rc" {
+                    if let PathArguments::AngleBracketed(a) = &last.arguments {
+                        if let Some(GenericArgument::Type(Type::Slice(_))) =
+                            a.args.iter().next()
+                        {
+                            return MyType::List;
+                        }
+                    }
+                }
+            }
         }
     }
     MyType::Other(ty)
 }
 
-fn generate_flag_context(long: &[&str], short: &[&str]) -> TokenStream {
+/// Returns `true` for lists and optional lists, which are parsed with a
+/// `ListCtx`
+fn is_list(ty: &MyType<'_>) -> bool {
+    match ty {
+        MyType::List => true,
+        MyType::Option(t) => matches!(parse_my_type(t), MyType::List),
+        MyType::Bool | MyType::Other(_) => false,
+    }
+}
+
+/// Returns `true` for arrays and optional arrays, which are parsed greedily
+fn is_array(ty: &MyType<'_>) -> bool {
+    match ty {
+        MyType::Bool | MyType::List => false,
+        MyType::Option(t) | MyType::Other(t) => matches!(t, Type::Array(_)),
+    }
+}
+
+fn generate_flag(long: &[&str], short: &[&str]) -> TokenStream {
     match (long.len(), short.len()) {
         (1, 1) => {
             let long = long[0];
             let short = short[0];
-            quote! { parkour::util::Flag::LongShort(#long, #short).into() }
+            quote! { parkour::util::Flag::LongShort(#long, #short) }
         }
         (0, 1) => {
             let short = short[0];
-            quote! { parkour::util::Flag::Short(#short).into() }
+            quote! { parkour::util::Flag::Short(#short) }
         }
         (1, 0) => {
             let long = long[0];
-            quote! { parkour::util::Flag::Long(#long).into() }
+            quote! { parkour::util::Flag::Long(#long) }
         }
         (_, _) => quote! {
             parkour::util::Flag::Many(vec![
                 #( parkour::util::Flag::Long(#long), )*
                 #( parkour::util::Flag::Short(#short), )*
-            ]).into()
+            ])
         },
     }
 }
@@ -264,6 +771,43 @@ fn flatten_flags<'a>(
     Ok((long, short))
 }
 
+/// Generates the code that enforces `arg(conflicts_with = "..")` and
+/// `arg(requires = "..")` after all arguments were parsed
+fn generate_relation_checks(
+    fields: &[(&Ident, String, TokenStream)],
+    relations: &[(&Ident, Relation, Span)],
+) -> Result<TokenStream> {
+    let find_field = |name: &str, span: Span| match fields.iter().find(|f| *f.0 == name) {
+        Some(field) => Ok(field),
+        None => bail!(span, "there is no field named {:?}", name),
+    };
+
+    let mut checks = Vec::new();
+    for (ident, relation, span) in relations {
+        let (_, a_str, a_is_set) = find_field(&ident.to_string(), *span)?;
+        checks.push(match relation {
+            Relation::ConflictsWith(other) => {
+                let (_, b_str, b_is_set) = find_field(other, *span)?;
+                quote! {
+                    if #a_is_set && #b_is_set {
+                        return Err(parkour::Error::conflicting_arguments(#a_str, #b_str));
+                    }
+                }
+            }
+            Relation::Requires(other) => {
+                let (_, b_str, b_is_set) = find_field(other, *span)?;
+                quote! {
+                    if #a_is_set && !(#b_is_set) {
+                        return Err(parkour::Error::missing_argument(#b_str)
+                            .chain(parkour::ErrorInner::InArgument(#a_str.to_string())));
+                    }
+                }
+            }
+        });
+    }
+    Ok(quote! { #( #checks )* })
+}
+
 fn get_subcommand_names(attrs: &[(Attr, Span)], name: &Ident) -> Result<Vec<String>> {
     let mut subcommands: Vec<String> = attrs
         .iter()
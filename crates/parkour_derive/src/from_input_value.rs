@@ -1,13 +1,29 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::spanned::Spanned;
-use syn::{DataEnum, Ident, Result, Variant};
+use syn::{Attribute, DataEnum, Ident, Result, Variant};
 
-use crate::utils;
+use crate::attrs::{Attr, Parkour};
+use crate::{attrs, utils};
 
-pub fn enums(name: &Ident, e: DataEnum) -> Result<TokenStream> {
+pub fn enums(name: &Ident, e: DataEnum, attrs: &[Attribute]) -> Result<TokenStream> {
     let variants: Vec<Variant> = e.variants.into_iter().collect();
 
+    let numeric = attrs::parse(attrs)?
+        .iter()
+        .any(|(a, _)| matches!(a, Attr::Parkour(Parkour::Numeric)));
+
+    // the variant at index `i` is also parsed from the string `i`
+    let numeric_variants = if numeric {
+        if let Some(v) = variants.iter().find(|&v| utils::field_len(&v.fields) != 0) {
+            bail!(v.fields.span(), "`parkour(numeric)` requires variants without fields");
+        }
+        variants.iter().map(|v| v.ident.clone()).collect()
+    } else {
+        Vec::new()
+    };
+    let numeric_strs = (0..numeric_variants.len()).map(|i| i.to_string());
+
     if let Some(v) = variants.iter().find(|&v| utils::field_len(&v.fields) > 1) {
         bail!(
             v.fields.span(),
@@ -15,26 +31,115 @@ pub fn enums(name: &Ident, e: DataEnum) -> Result<TokenStream> {
         )
     }
 
+    let mut catch_all = None;
+    let mut default = None;
+    let mut aliases = Vec::new();
+    let mut other_variants = Vec::new();
+    for v in variants {
+        let mut attrs = attrs::parse(&v.attrs)?;
+
+        // aliases are accepted in addition to the variant name
+        let mut alias_span = None;
+        attrs.retain(|(a, span)| match a {
+            Attr::Parkour(Parkour::Alias(alias)) => {
+                aliases.push((alias.to_lowercase(), v.ident.clone()));
+                alias_span = Some(*span);
+                false
+            }
+            _ => true,
+        });
+        if let Some(span) = alias_span {
+            if utils::field_len(&v.fields) != 0 {
+                bail!(span, "`parkour(alias)` requires a variant without fields");
+            }
+        }
+
+        if let Some((_, span)) =
+            attrs.iter().find(|(a, _)| matches!(a, Attr::Parkour(Parkour::Default(None))))
+        {
+            if default.is_some() {
+                bail!(*span, "`parkour(default)` can only be used once");
+            }
+            if utils::field_len(&v.fields) != 0 {
+                bail!(*span, "`parkour(default)` requires a variant without fields");
+            }
+            default = Some(v.ident.clone());
+            other_variants.push(v);
+        } else if let Some((_, span)) =
+            attrs.iter().find(|(a, _)| matches!(a, Attr::Parkour(Parkour::CatchAll)))
+        {
+            if catch_all.is_some() {
+                bail!(*span, "`parkour(catch_all)` can only be used once");
+            }
+            if utils::field_len(&v.fields) != 1 {
+                bail!(*span, "`parkour(catch_all)` requires a variant with 1 field");
+            }
+            catch_all = Some(v);
+        } else if let Some((_, span)) = attrs.first() {
+            bail!(*span, "this key is not supported on variants");
+        } else {
+            other_variants.push(v);
+        }
+    }
+    let variants = other_variants;
+
     let empty_idents = utils::get_empty_variant_idents(&variants);
     let empty_ident_strs = utils::get_lowercase_ident_strs(&empty_idents);
     let (inner_types, inner_type_ctors) = utils::get_variant_types_and_ctors(&variants)?;
 
-    let empty_ident_comparisons = empty_ident_strs.iter().map(|s| {
-        if s.chars().all(|c| c.is_ascii()) {
+    let fallback = match &catch_all {
+        Some(v) => {
+            let (types, ctors) =
+                utils::get_variant_types_and_ctors(std::slice::from_ref(v))?;
+            let (ty, ctor) = (types[0], &ctors[0]);
+            quote! {
+                let __v = <#ty as ::std::convert::From<&str>>::from(v);
+                Ok(#name::#ctor)
+            }
+        }
+        None => quote! {
+            match source {
+                Some(s) => Err(
+                    parkour::Error::unexpected_value(v, Self::possible_values(context))
+                        .with_source(s),
+                ),
+                None => Err(parkour::Error::unexpected_value(v, Self::possible_values(context))),
+            }
+        },
+    };
+
+    let comparison = |s: &String| {
+        if s.is_ascii() {
             quote! { v if v.eq_ignore_ascii_case(#s) }
         } else {
             quote! { v if v.to_lowercase() == #s }
         }
-    });
+    };
+    let empty_ident_comparisons = empty_ident_strs.iter().map(comparison);
+    let alias_comparisons = aliases.iter().map(|(s, _)| comparison(s));
+    let alias_idents = aliases.iter().map(|(_, ident)| ident);
+
+    // an empty value, e.g. `--color=`, is parsed as the default variant
+    let default_arm = match &default {
+        Some(ident) => quote! { "" => Ok(#name::#ident {}), },
+        None => quote! {},
+    };
 
     let from_input_value = quote! {
         fn from_input_value(value: &str, context: &Self::Context) -> parkour::Result<Self> {
             match value {
+                #default_arm
                 #(
                     #empty_ident_comparisons => Ok(#name::#empty_idents {}),
                 )*
+                #(
+                    #alias_comparisons => Ok(#name::#alias_idents {}),
+                )*
+                #(
+                    #numeric_strs => Ok(#name::#numeric_variants {}),
+                )*
                 v => {
-                    #[allow(unused_mut)]
+                    #[allow(unused_mut, unused_variables)]
                     let mut source = None::<parkour::Error>;
                     #(
                         match <#inner_types as parkour::FromInputValue>::from_input_value(
@@ -48,13 +153,7 @@ pub fn enums(name: &Ident, e: DataEnum) -> Result<TokenStream> {
                             },
                         }
                     )*
-                    match source {
-                        Some(s) => Err(
-                            parkour::Error::unexpected_value(v, Self::possible_values(context))
-                                .with_source(s),
-                        ),
-                        None => Err(parkour::Error::unexpected_value(v, Self::possible_values(context))),
-                    }
+                    #fallback
                 }
             }
         }
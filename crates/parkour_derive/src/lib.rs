@@ -13,7 +13,7 @@ mod parse_attrs;
 mod from_input;
 mod from_input_value;
 
-#[proc_macro_derive(FromInputValue)]
+#[proc_macro_derive(FromInputValue, attributes(parkour))]
 pub fn from_input_value_derive(input: TokenStream) -> TokenStream {
     let ast = syn::parse_macro_input!(input as DeriveInput);
     let name = &ast.ident;
@@ -27,7 +27,7 @@ pub fn from_input_value_derive(input: TokenStream) -> TokenStream {
     }
 
     match ast.data {
-        Data::Enum(e) => match from_input_value::enums(name, e) {
+        Data::Enum(e) => match from_input_value::enums(name, e, &ast.attrs) {
             Ok(stream) => stream.into(),
             Err(err) => err.into_compile_error().into(),
         },
@@ -1,7 +1,9 @@
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, ToTokens};
 use syn::spanned::Spanned;
-use syn::{Field, Fields, Ident, Result, Type, Variant};
+use syn::{Expr, Field, Fields, Ident, Result, Type, Variant};
+
+use crate::attrs::{Attr, Parkour};
 
 macro_rules! bail_main {
     ($span:expr, $s:literal $(,)?) => {{
@@ -43,6 +45,46 @@ pub fn ident_to_flag_string(ident: &Ident) -> String {
     ident.to_string().trim_matches('_').replace('_', "-")
 }
 
+/// Generates the code that handles `-h` and `--help` if the type has a
+/// `parkour(auto_help)` attribute. Without a printer function, `help_text` is
+/// printed to stdout; it must be an expression evaluating to a type that
+/// implements `Display`.
+pub fn generate_auto_help(
+    attrs: &[(Attr, Span)],
+    help_text: TokenStream,
+) -> Result<TokenStream> {
+    let mut printers = attrs.iter().filter_map(|(a, span)| match a {
+        Attr::Parkour(Parkour::AutoHelp(p)) => Some((p, *span)),
+        _ => None,
+    });
+
+    let printer: Option<&Option<Box<Expr>>> = match printers.next() {
+        Some((p, _)) => {
+            if let Some((_, span)) = printers.next() {
+                bail!(span, "`parkour(auto_help)` is specified twice");
+            }
+            Some(p)
+        }
+        None => None,
+    };
+
+    Ok(match printer {
+        None => quote! {},
+        Some(printer) => {
+            let print = match printer {
+                Some(f) => quote! { #f(); },
+                None => quote! { println!("{}", #help_text); },
+            };
+            quote! {
+                if input.parse_help_flag()? {
+                    #print
+                    return Err(parkour::Error::early_exit());
+                }
+            }
+        }
+    })
+}
+
 pub fn get_empty_variant_idents(variants: &[Variant]) -> Vec<&Ident> {
     variants.iter().filter(|&v| field_len(&v.fields) == 0).map(|v| &v.ident).collect()
 }
@@ -1,10 +1,15 @@
 use palex::ArgsInput;
 
-use crate::util::Flag;
+use crate::util::{EnablePairCtx, Flag};
 use crate::ErrorInner;
 
 use super::{Action, ApplyResult, Reset, Set, SetOnce, Unset};
 
+/// Short boolean flags can be combined, e.g. `-vx` is equivalent to `-v -x`.
+/// After a flag was applied, the rest of the argument (`x`) remains in the
+/// input, so it can be parsed as the next flag. If it isn't a known flag,
+/// [`Parse::expect_empty`](crate::Parse::expect_empty) reports it as an
+/// unexpected value.
 impl<'a> Action<Flag<'a>> for Set<'_, bool> {
     fn apply(self, input: &mut ArgsInput, context: &Flag<'a>) -> ApplyResult {
         if Flag::from_input(input, context)? {
@@ -16,6 +21,20 @@ impl<'a> Action<Flag<'a>> for Set<'_, bool> {
     }
 }
 
+impl<'a> Action<EnablePairCtx<'a>> for Set<'_, bool> {
+    fn apply(self, input: &mut ArgsInput, context: &EnablePairCtx<'a>) -> ApplyResult {
+        if Flag::from_input(input, &context.enable)? {
+            *self.0 = true;
+            Ok(true)
+        } else if Flag::from_input(input, &context.disable)? {
+            *self.0 = false;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+}
+
 impl<'a> Action<Flag<'a>> for Reset<'_, bool> {
     fn apply(self, input: &mut ArgsInput, context: &Flag<'a>) -> ApplyResult {
         if Flag::from_input(input, context)? {
@@ -0,0 +1,123 @@
+use std::collections::{BTreeSet, HashSet, LinkedList, VecDeque};
+use std::hash::Hash;
+use std::rc::Rc;
+
+use palex::ArgsInput;
+
+use crate::impls::ListCtx;
+use crate::util::PosCtx;
+use crate::{ErrorInner, FromInputValue, Parse};
+
+use super::{Action, Append, ApplyResult, SetPositional};
+
+macro_rules! impl_append {
+    ($( $t:ident $(+ $bound:ident)* ),* $(,)?) => {
+        $(
+            impl<'a, T: FromInputValue<'a> $(+ $bound)*> Action<ListCtx<'a, T::Context>>
+                for Append<'_, $t<T>>
+            {
+                fn apply(
+                    self,
+                    input: &mut ArgsInput,
+                    context: &ListCtx<'a, T::Context>,
+                ) -> ApplyResult {
+                    match input.try_parse::<$t<T>>(context).map_err(|e| {
+                        e.chain(ErrorInner::InArgument(context.flag.first_to_string()))
+                    })? {
+                        Some(values) => {
+                            self.0.extend(values);
+                            Ok(true)
+                        }
+                        None => Ok(false),
+                    }
+                }
+            }
+
+            impl<'a, T: FromInputValue<'a> $(+ $bound)*> Action<ListCtx<'a, T::Context>>
+                for Append<'_, Option<$t<T>>>
+            {
+                fn apply(
+                    self,
+                    input: &mut ArgsInput,
+                    context: &ListCtx<'a, T::Context>,
+                ) -> ApplyResult {
+                    match self.0 {
+                        Some(values) => Append(values).apply(input, context),
+                        None => {
+                            let mut values = $t::new();
+                            let applied = Append(&mut values).apply(input, context)?;
+                            if applied {
+                                *self.0 = Some(values);
+                            }
+                            Ok(applied)
+                        }
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_append! {
+    Vec,
+    VecDeque,
+    LinkedList,
+    BTreeSet + Ord,
+    HashSet + Hash + Eq,
+}
+
+impl<'a, T: FromInputValue<'a>> Action<ListCtx<'a, T::Context>> for Append<'_, Box<[T]>> {
+    fn apply(
+        self,
+        input: &mut ArgsInput,
+        context: &ListCtx<'a, T::Context>,
+    ) -> ApplyResult {
+        let mut values = std::mem::take(self.0).into_vec();
+        let applied = Append(&mut values).apply(input, context);
+        *self.0 = values.into_boxed_slice();
+        applied
+    }
+}
+
+impl<'a, T: FromInputValue<'a> + Clone> Action<ListCtx<'a, T::Context>>
+    for Append<'_, Rc<[T]>>
+{
+    fn apply(
+        self,
+        input: &mut ArgsInput,
+        context: &ListCtx<'a, T::Context>,
+    ) -> ApplyResult {
+        let mut values = self.0.to_vec();
+        let applied = Append(&mut values).apply(input, context)?;
+        if applied {
+            *self.0 = values.into();
+        }
+        Ok(applied)
+    }
+}
+
+impl<'a, T: FromInputValue<'a>> Action<PosCtx<'a, T::Context>>
+    for SetPositional<'_, Vec<T>>
+{
+    fn apply(
+        self,
+        input: &mut ArgsInput,
+        context: &PosCtx<'a, T::Context>,
+    ) -> ApplyResult {
+        if let Some(s) = input.try_parse_value(&context.inner)? {
+            if let Some(max) = context.max_occurrences {
+                if self.0.len() >= max as usize {
+                    return Err(ErrorInner::TooManyArgOccurrences {
+                        arg: context.name.to_string(),
+                        max: Some(max),
+                    }
+                    .into());
+                }
+            }
+            self.0.push(s);
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use palex::ArgsInput;
+
+use crate::impls::ListCtx;
+use crate::{ErrorInner, FromInputValue, Parse};
+
+use super::{Action, Append, ApplyResult};
+
+impl<'a, K, V, C> Action<ListCtx<'a, C>> for Append<'_, HashMap<K, V>>
+where
+    (K, V): FromInputValue<'a, Context = C>,
+    K: Hash + Eq,
+    C: 'a,
+{
+    fn apply(self, input: &mut ArgsInput, context: &ListCtx<'a, C>) -> ApplyResult {
+        match input.try_parse::<HashMap<K, V>>(context).map_err(|e| {
+            e.chain(ErrorInner::InArgument(context.flag.first_to_string()))
+        })? {
+            Some(entries) => {
+                self.0.extend(entries);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+}
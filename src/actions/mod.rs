@@ -23,6 +23,8 @@ use palex::ArgsInput;
 use crate::{Error, FromInput, FromInputValue, Parse};
 
 mod bool;
+mod list;
+mod map;
 mod option;
 
 /// The result of [`Action::apply`]
@@ -60,6 +62,9 @@ pub struct Inc<'a, T>(pub &'a mut T);
 pub struct Dec<'a, T>(pub &'a mut T);
 
 /// Appends the parsed value(s) to the existing ones.
+///
+/// For an `Option` of a list, the value stays `None` until the argument
+/// appears for the first time.
 pub struct Append<'a, T>(pub &'a mut T);
 
 /// Like [`Set`], but works for positional arguments.
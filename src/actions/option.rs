@@ -93,7 +93,7 @@ impl<'a, T: FromInputValue<'a>> Action<PosCtx<'a, T::Context>>
             if self.0.is_some() {
                 return Err(ErrorInner::TooManyArgOccurrences {
                     arg: context.name.to_string(),
-                    max: None,
+                    max: Some(1),
                 }
                 .into());
             }
@@ -1,7 +1,10 @@
+use std::borrow::Cow;
+use std::char::ParseCharError;
 use std::fmt;
 use std::num::{ParseFloatError, ParseIntError};
+use std::str::ParseBoolError;
 
-use crate::help::PossibleValues;
+use crate::help::{PossibleValue, PossibleValues};
 use crate::util::Flag;
 
 /// The error type when parsing command-line arguments. You can create an
@@ -79,6 +82,53 @@ impl Error {
         &self.inner
     }
 
+    /// Returns `true` if the [`ErrorInner`] of this error equals `other`. The
+    /// source chain is ignored. This is useful in tests:
+    ///
+    /// ```
+    /// use parkour::{Error, ErrorInner};
+    ///
+    /// let e = Error::no_value().chain(ErrorInner::InSubcommand("test".into()));
+    /// assert!(e.kind_eq(&ErrorInner::NoValue));
+    /// ```
+    pub fn kind_eq(&self, other: &ErrorInner) -> bool {
+        self.inner == *other
+    }
+
+    /// Returns an iterator over the chained sources of this error, starting
+    /// with the direct source. The error itself is not included.
+    ///
+    /// ### Usage
+    ///
+    /// ```
+    /// use parkour::{Error, ErrorInner};
+    ///
+    /// let e = Error::missing_value().chain(ErrorInner::InSubcommand("test".into()));
+    /// for source in e.sources() {
+    ///     eprint!(": {}", source);
+    /// }
+    /// ```
+    pub fn sources(&self) -> impl Iterator<Item = &(dyn std::error::Error + 'static)> {
+        std::iter::successors(std::error::Error::source(self), |s| s.source())
+    }
+
+    /// Returns only the primary message of this error, without the context
+    /// from chained sources such as ``in `--flag` ``. Unlike
+    /// [`crate::print_error`], this is suited for concise user interfaces.
+    ///
+    /// This is identical to the [`Display`](fmt::Display) implementation,
+    /// which never includes the sources; use [`Error::sources`] to get them.
+    ///
+    /// ```
+    /// use parkour::{Error, ErrorInner};
+    ///
+    /// let e = Error::missing_value().chain(ErrorInner::InArgument("--out".into()));
+    /// assert_eq!(e.message_only(), "missing value");
+    /// ```
+    pub fn message_only(&self) -> String {
+        self.to_string()
+    }
+
     /// Create a `EarlyExit` error
     pub fn early_exit() -> Self {
         ErrorInner::EarlyExit.into()
@@ -89,6 +139,59 @@ impl Error {
         matches!(self.inner, ErrorInner::EarlyExit)
     }
 
+    /// Create a `Version` error, which contains the version string that should
+    /// be printed
+    pub fn version(version: impl ToString) -> Self {
+        ErrorInner::Version(version.to_string()).into()
+    }
+
+    /// Returns `true` if this is a `Version` error
+    pub fn is_version(&self) -> bool {
+        matches!(self.inner, ErrorInner::Version(_))
+    }
+
+    /// Create a `HelpTopic` error, which contains the topic for which help
+    /// was requested, e.g. `net` for `--help=net`
+    pub fn help_topic(topic: impl ToString) -> Self {
+        ErrorInner::HelpTopic(topic.to_string()).into()
+    }
+
+    /// Returns `true` if this is a `HelpTopic` error
+    pub fn is_help_topic(&self) -> bool {
+        matches!(self.inner, ErrorInner::HelpTopic(_))
+    }
+
+    /// Returns `true` if this error controls the parsing instead of reporting
+    /// a problem: [`Error::no_value`], [`Error::early_exit`],
+    /// [`Error::version`] and [`Error::help_topic`] errors. These should be
+    /// propagated unchanged instead of being stored or reported.
+    pub fn is_control_flow(&self) -> bool {
+        self.is_no_value()
+            || self.is_early_exit()
+            || self.is_version()
+            || self.is_help_topic()
+    }
+
+    /// Returns `true` if this error is about the value of an argument, e.g.
+    /// because it couldn't be parsed. Errors about the argument itself, such
+    /// as [`ErrorInner::TooManyArgOccurrences`], return `false`.
+    pub fn is_value_error(&self) -> bool {
+        matches!(
+            self.inner,
+            ErrorInner::IncompleteValue(_)
+                | ErrorInner::InvalidValue { .. }
+                | ErrorInner::TooManyValues { .. }
+                | ErrorInner::TooManyBytes { .. }
+                | ErrorInner::WrongNumberOfValues { .. }
+                | ErrorInner::UnexpectedValue { .. }
+                | ErrorInner::Custom(_)
+                | ErrorInner::ParseIntError(_)
+                | ErrorInner::ParseFloatError(_)
+                | ErrorInner::ParseCharError(_)
+                | ErrorInner::ParseBoolError(_)
+        )
+    }
+
     /// Create a `UnexpectedValue` error
     pub fn unexpected_value(
         got: impl ToString,
@@ -102,6 +205,47 @@ impl Error {
         ErrorInner::MissingArgument { arg: arg.to_string() }.into()
     }
 
+    /// Create a `MissingArguments` error
+    pub fn missing_arguments<S: ToString>(args: impl IntoIterator<Item = S>) -> Self {
+        ErrorInner::MissingArguments {
+            args: args.into_iter().map(|arg| arg.to_string()).collect(),
+        }
+        .into()
+    }
+
+    /// Create a `UnexpectedArguments` error
+    pub fn unexpected_arguments<S: ToString>(args: impl IntoIterator<Item = S>) -> Self {
+        ErrorInner::UnexpectedArguments {
+            args: args.into_iter().map(|arg| arg.to_string()).collect(),
+        }
+        .into()
+    }
+
+    /// Create a `ConflictingArguments` error
+    pub fn conflicting_arguments(a: impl ToString, b: impl ToString) -> Self {
+        ErrorInner::ConflictingArguments { a: a.to_string(), b: b.to_string() }.into()
+    }
+
+    /// Create a `Custom` error with an arbitrary message, e.g. for validation
+    /// in a custom [`crate::FromInputValue`] implementation
+    ///
+    /// ### Usage
+    ///
+    /// ```
+    /// use parkour::Error;
+    ///
+    /// let e = Error::custom("port 0 is reserved");
+    /// assert_eq!(e.to_string(), "port 0 is reserved");
+    /// ```
+    pub fn custom(message: impl Into<String>) -> Self {
+        ErrorInner::Custom(message.into()).into()
+    }
+
+    /// Create a `RequiresValue` error
+    pub fn requires_value(flag: &Flag) -> Self {
+        ErrorInner::RequiresValue { flag: flag.first_to_string() }.into()
+    }
+
     /// Create a `InArgument` error
     pub fn in_argument(flag: &Flag) -> Self {
         ErrorInner::InArgument(flag.first_to_string()).into()
@@ -112,6 +256,142 @@ impl Error {
         ErrorInner::InSubcommand(cmd.to_string()).into()
     }
 
+    /// Serializes the error and its sources to a JSON object. This is useful
+    /// for tools that wrap a command-line program. The object always has a
+    /// `kind` (e.g. `"missing_argument"`) and a `message` field. Additional
+    /// fields depend on the kind of error. If the error has a source, it is
+    /// included in the `source` field.
+    ///
+    /// ### Usage
+    ///
+    /// ```
+    /// use parkour::Error;
+    ///
+    /// assert_eq!(
+    ///     Error::missing_argument("--out").to_json(),
+    ///     r#"{"kind":"missing_argument","message":"required --out was not provided","flag":"--out"}"#,
+    /// );
+    /// ```
+    pub fn to_json(&self) -> String {
+        let mut buf = String::new();
+        self.write_json(&mut buf);
+        buf
+    }
+
+    fn write_json(&self, buf: &mut String) {
+        let mut obj = JsonObject::new(buf);
+        let kind = match &self.inner {
+            ErrorInner::NoValue => "no_value",
+            ErrorInner::MissingValue => "missing_value",
+            ErrorInner::RequiresValue { .. } => "requires_value",
+            ErrorInner::IncompleteValue(_) => "incomplete_value",
+            ErrorInner::EarlyExit => "early_exit",
+            ErrorInner::Version(_) => "version",
+            ErrorInner::HelpTopic(_) => "help_topic",
+            ErrorInner::InArgument(_) => "in_argument",
+            ErrorInner::InSubcommand(_) => "in_subcommand",
+            ErrorInner::InvalidValue { .. } => "invalid_value",
+            ErrorInner::TooManyValues { .. } => "too_many_values",
+            ErrorInner::TooManyBytes { .. } => "too_many_bytes",
+            ErrorInner::WrongNumberOfValues { .. } => "wrong_number_of_values",
+            ErrorInner::MissingArgument { .. } => "missing_argument",
+            ErrorInner::MissingArguments { .. } => "missing_arguments",
+            ErrorInner::UnexpectedArgument { .. } => "unexpected_argument",
+            ErrorInner::UnexpectedArguments { .. } => "unexpected_arguments",
+            ErrorInner::UnexpectedValue { .. } => "unexpected_value",
+            ErrorInner::TooManyArgOccurrences { .. } => "too_many_arg_occurrences",
+            ErrorInner::ConflictingArguments { .. } => "conflicting_arguments",
+            ErrorInner::MultipleErrors(_) => "multiple_errors",
+            ErrorInner::Custom(_) => "custom",
+            ErrorInner::ParseIntError(_) => "parse_int_error",
+            ErrorInner::ParseFloatError(_) => "parse_float_error",
+            ErrorInner::ParseCharError(_) => "parse_char_error",
+            ErrorInner::ParseBoolError(_) => "parse_bool_error",
+        };
+        obj.string("kind", kind);
+        obj.string("message", &self.to_string());
+
+        match &self.inner {
+            | ErrorInner::NoValue
+            | ErrorInner::MissingValue
+            | ErrorInner::EarlyExit
+            | ErrorInner::Custom(_)
+            | ErrorInner::ParseIntError(_)
+            | ErrorInner::ParseFloatError(_)
+            | ErrorInner::ParseCharError(_)
+            | ErrorInner::ParseBoolError(_) => {}
+            ErrorInner::IncompleteValue(part) => obj.raw("part", &part.to_string()),
+            ErrorInner::Version(version) => obj.string("version", version),
+            ErrorInner::HelpTopic(topic) => obj.string("topic", topic),
+            ErrorInner::InArgument(flag) => obj.string("flag", flag),
+            ErrorInner::RequiresValue { flag } => obj.string("flag", flag),
+            ErrorInner::InSubcommand(cmd) => obj.string("subcommand", cmd),
+            ErrorInner::InvalidValue { got, expected } => {
+                obj.string("got", got);
+                if let Some(expected) = expected {
+                    let values: Vec<Cow<'_, str>> = expected
+                        .dedup()
+                        .into_iter()
+                        .map(|v| match v {
+                            PossibleValue::String(s) | PossibleValue::Other(s) => {
+                                Cow::Borrowed(s)
+                            }
+                            PossibleValue::Range { .. } => Cow::Owned(v.to_string()),
+                        })
+                        .collect();
+                    obj.strings("expected", values.iter().map(|v| &**v));
+                }
+            }
+            ErrorInner::TooManyValues { max, count } => {
+                obj.raw("max", &max.to_string());
+                obj.raw("count", &count.to_string());
+            }
+            ErrorInner::TooManyBytes { max, count } => {
+                obj.raw("max", &max.to_string());
+                obj.raw("count", &count.to_string());
+            }
+            ErrorInner::WrongNumberOfValues { expected, got } => {
+                obj.raw("expected", &expected.to_string());
+                obj.raw("got", &got.to_string());
+            }
+            ErrorInner::MissingArgument { arg } => obj.string("flag", arg),
+            ErrorInner::MissingArguments { args } => {
+                obj.strings("flags", args.iter().map(String::as_str))
+            }
+            ErrorInner::UnexpectedArgument { arg } => obj.string("argument", arg),
+            ErrorInner::UnexpectedArguments { args } => {
+                obj.strings("arguments", args.iter().map(String::as_str))
+            }
+            ErrorInner::UnexpectedValue { value } => obj.string("value", value),
+            ErrorInner::TooManyArgOccurrences { arg, max } => {
+                obj.string("flag", arg);
+                match max {
+                    Some(max) => obj.raw("max", &max.to_string()),
+                    None => obj.raw("max", "null"),
+                }
+            }
+            ErrorInner::ConflictingArguments { a, b } => {
+                obj.string("a", a);
+                obj.string("b", b);
+            }
+            ErrorInner::MultipleErrors(count) => obj.raw("count", &count.to_string()),
+        }
+
+        if let Some(source) = &self.source {
+            obj.key("source");
+            match source.downcast_ref::<Error>() {
+                Some(source) => source.write_json(obj.buf),
+                None => {
+                    let mut source_obj = JsonObject::new(obj.buf);
+                    source_obj.string("kind", "other");
+                    source_obj.string("message", &source.to_string());
+                    source_obj.end();
+                }
+            }
+        }
+        obj.end();
+    }
+
     /// Create a `TooManyArgOccurrences` error
     pub fn too_many_arg_occurrences(arg: impl ToString, max: Option<u32>) -> Self {
         ErrorInner::TooManyArgOccurrences { arg: arg.to_string(), max }.into()
@@ -135,12 +415,27 @@ pub enum ErrorInner {
     /// but was required
     MissingValue,
 
+    /// A flag that requires a value was provided without a value, e.g. `--out`
+    /// at the end of the input
+    RequiresValue {
+        /// The flag that requires a value
+        flag: String,
+    },
+
     /// The argument you tried to parse was only partly present
     IncompleteValue(usize),
 
     /// Used when an argument should abort argument parsing, like --help
     EarlyExit,
 
+    /// Used when the --version flag was encountered. Contains the version
+    /// string, which is also what is displayed
+    Version(String),
+
+    /// Used when help for a specific topic was requested, e.g. with
+    /// `--help=net`. Contains the topic
+    HelpTopic(String),
+
     /// Indicates that the error originated in the specified argument. This
     /// should be used as the source for another error
     InArgument(String),
@@ -166,6 +461,14 @@ pub enum ErrorInner {
         count: usize,
     },
 
+    /// The values of a list are longer in total than allowed
+    TooManyBytes {
+        /// The maximum number of bytes
+        max: usize,
+        /// The number of bytes that was encountered
+        count: usize,
+    },
+
     /// The parsed array has the wrong length
     WrongNumberOfValues {
         /// The length of the array
@@ -180,12 +483,24 @@ pub enum ErrorInner {
         arg: String,
     },
 
+    /// Several required arguments were not provided
+    MissingArguments {
+        /// The names of the arguments that are missing
+        args: Vec<String>,
+    },
+
     /// An unknown argument was provided
     UnexpectedArgument {
         /// The (full) argument that wasn't expected
         arg: String,
     },
 
+    /// Several unknown arguments were provided
+    UnexpectedArguments {
+        /// The (full) arguments that weren't expected
+        args: Vec<String>,
+    },
+
     /// The argument has a value, but no value was expected
     UnexpectedValue {
         /// The value of the argument
@@ -200,11 +515,32 @@ pub enum ErrorInner {
         max: Option<u32>,
     },
 
+    /// Two arguments were provided that can't be used together
+    ConflictingArguments {
+        /// The argument that was checked
+        a: String,
+        /// The argument it conflicts with
+        b: String,
+    },
+
+    /// Several errors were collected while parsing. The errors can be obtained
+    /// with [`crate::Parse::take_errors`]
+    MultipleErrors(usize),
+
+    /// A custom error with an arbitrary message, see [`Error::custom`]
+    Custom(String),
+
     /// Parsing an integer failed
     ParseIntError(ParseIntError),
 
     /// Parsing a floating-point number failed
     ParseFloatError(ParseFloatError),
+
+    /// Parsing a character failed
+    ParseCharError(ParseCharError),
+
+    /// Parsing a boolean with [`str::parse`] failed
+    ParseBoolError(ParseBoolError),
 }
 
 impl From<ParseIntError> for Error {
@@ -217,6 +553,16 @@ impl From<ParseFloatError> for Error {
         ErrorInner::ParseFloatError(e).into()
     }
 }
+impl From<ParseCharError> for Error {
+    fn from(e: ParseCharError) -> Self {
+        ErrorInner::ParseCharError(e).into()
+    }
+}
+impl From<ParseBoolError> for Error {
+    fn from(e: ParseBoolError) -> Self {
+        ErrorInner::ParseBoolError(e).into()
+    }
+}
 
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
@@ -232,10 +578,17 @@ impl fmt::Display for Error {
         match &self.inner {
             ErrorInner::NoValue => write!(f, "no value"),
             ErrorInner::MissingValue => write!(f, "missing value"),
+            ErrorInner::RequiresValue { flag } => {
+                write!(f, "argument {} requires a value", flag)
+            }
             ErrorInner::IncompleteValue(part) => {
                 write!(f, "missing part {} of value", part)
             }
             ErrorInner::EarlyExit => write!(f, "early exit"),
+            ErrorInner::Version(version) => f.write_str(version),
+            ErrorInner::HelpTopic(topic) => {
+                write!(f, "help for topic `{}` was requested", topic.escape_debug())
+            }
             ErrorInner::InArgument(opt) => write!(f, "in `{}`", opt.escape_debug()),
             ErrorInner::InSubcommand(cmd) => {
                 write!(f, "in subcommand {}", cmd.escape_debug())
@@ -255,18 +608,44 @@ impl fmt::Display for Error {
             ErrorInner::UnexpectedArgument { arg } => {
                 write!(f, "unexpected argument `{}`", arg.escape_debug())
             }
+            ErrorInner::UnexpectedArguments { args } => {
+                let args: Vec<String> =
+                    args.iter().map(|arg| format!("`{}`", arg.escape_debug())).collect();
+                if args.len() == 1 {
+                    f.write_str("unexpected argument ")?;
+                } else {
+                    f.write_str("unexpected arguments ")?;
+                }
+                write_list(f, &args)
+            }
             ErrorInner::UnexpectedValue { value } => {
                 write!(f, "unexpected value `{}`", value.escape_debug())
             }
             ErrorInner::TooManyValues { max, count } => {
                 write!(f, "too many values, expected at most {}, got {}", max, count)
             }
+            ErrorInner::TooManyBytes { max, count } => {
+                write!(
+                    f,
+                    "values are too long, expected at most {} bytes, got {}",
+                    max, count
+                )
+            }
             ErrorInner::WrongNumberOfValues { expected, got } => {
                 write!(f, "wrong number of values, expected {}, got {}", expected, got)
             }
             ErrorInner::MissingArgument { arg } => {
                 write!(f, "required {} was not provided", arg)
             }
+            ErrorInner::MissingArguments { args } => {
+                f.write_str("required ")?;
+                write_list(f, args)?;
+                if args.len() == 1 {
+                    f.write_str(" was not provided")
+                } else {
+                    f.write_str(" were not provided")
+                }
+            }
             ErrorInner::TooManyArgOccurrences { arg, max } => {
                 if let Some(max) = max {
                     write!(
@@ -278,9 +657,216 @@ impl fmt::Display for Error {
                     write!(f, "{} was used too often", arg)
                 }
             }
+            ErrorInner::MultipleErrors(count) => write!(f, "{} errors occurred", count),
+            ErrorInner::Custom(message) => f.write_str(message),
+            ErrorInner::ConflictingArguments { a, b } => {
+                write!(f, "argument {} cannot be used with {}", a, b)
+            }
 
             ErrorInner::ParseIntError(e) => write!(f, "{}", e),
             ErrorInner::ParseFloatError(e) => write!(f, "{}", e),
+            ErrorInner::ParseCharError(e) => write!(f, "{}", e),
+            ErrorInner::ParseBoolError(e) => write!(f, "{}", e),
         }
     }
 }
+
+/// Helper for writing a JSON object
+struct JsonObject<'a> {
+    buf: &'a mut String,
+    first: bool,
+}
+
+impl<'a> JsonObject<'a> {
+    fn new(buf: &'a mut String) -> Self {
+        buf.push('{');
+        JsonObject { buf, first: true }
+    }
+
+    fn key(&mut self, key: &str) {
+        if !self.first {
+            self.buf.push(',');
+        }
+        self.first = false;
+        write_json_string(self.buf, key);
+        self.buf.push(':');
+    }
+
+    fn raw(&mut self, key: &str, value: &str) {
+        self.key(key);
+        self.buf.push_str(value);
+    }
+
+    fn string(&mut self, key: &str, value: &str) {
+        self.key(key);
+        write_json_string(self.buf, value);
+    }
+
+    fn strings<'b>(&mut self, key: &str, values: impl Iterator<Item = &'b str>) {
+        self.key(key);
+        self.buf.push('[');
+        for (i, value) in values.enumerate() {
+            if i > 0 {
+                self.buf.push(',');
+            }
+            write_json_string(self.buf, value);
+        }
+        self.buf.push(']');
+    }
+
+    fn end(self) {
+        self.buf.push('}');
+    }
+}
+
+fn write_json_string(buf: &mut String, s: &str) {
+    buf.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            c if (c as u32) < 0x20 => buf.push_str(&format!("\\u{:04x}", c as u32)),
+            c => buf.push(c),
+        }
+    }
+    buf.push('"');
+}
+
+/// Writes a list of items like `a, b and c`
+fn write_list(f: &mut fmt::Formatter<'_>, items: &[String]) -> fmt::Result {
+    let mut iter = items.iter().peekable();
+    match iter.next() {
+        Some(item) => {
+            f.write_str(item)?;
+            while let Some(next) = iter.next() {
+                if iter.peek().is_some() {
+                    f.write_str(", ")?;
+                } else {
+                    f.write_str(" and ")?;
+                }
+                f.write_str(next)?;
+            }
+            Ok(())
+        }
+        None => f.write_str("nothing"),
+    }
+}
+
+#[test]
+fn test_missing_arguments() {
+    let e = Error::missing_arguments(["--out"]);
+    assert_eq!(e.to_string(), "required --out was not provided");
+    let e = Error::missing_arguments(["--out", "pos1"]);
+    assert_eq!(e.to_string(), "required --out and pos1 were not provided");
+    let e = Error::missing_arguments(["--in", "--out", "pos1"]);
+    assert_eq!(e.to_string(), "required --in, --out and pos1 were not provided");
+}
+
+#[test]
+fn test_unexpected_arguments() {
+    let e: Error = ErrorInner::UnexpectedArguments { args: vec!["foo".into()] }.into();
+    assert_eq!(e.to_string(), "unexpected argument `foo`");
+    let e: Error =
+        ErrorInner::UnexpectedArguments { args: vec!["foo".into(), "--bar".into()] }
+            .into();
+    assert_eq!(e.to_string(), "unexpected arguments `foo` and `--bar`");
+}
+
+#[test]
+fn test_parse_error_conversions() {
+    fn parse_char(value: &str) -> Result<char, Error> {
+        Ok(value.parse::<char>()?)
+    }
+    fn parse_bool(value: &str) -> Result<bool, Error> {
+        Ok(value.parse::<bool>()?)
+    }
+
+    assert_eq!(parse_char("x").unwrap(), 'x');
+    let e = parse_char("xy").unwrap_err();
+    assert!(matches!(e.inner(), ErrorInner::ParseCharError(_)));
+    assert_eq!(e.to_string(), "too many characters in string");
+
+    let e = parse_bool("yes").unwrap_err();
+    assert!(matches!(e.inner(), ErrorInner::ParseBoolError(_)));
+    assert_eq!(
+        e.to_json(),
+        r#"{"kind":"parse_bool_error","message":"provided string was not `true` or `false`"}"#
+    );
+}
+
+#[test]
+fn test_sources() {
+    let e = Error::missing_value()
+        .chain(ErrorInner::InArgument("--id".into()))
+        .chain(ErrorInner::InSubcommand("show".into()));
+    let sources: Vec<String> = e.sources().map(|s| s.to_string()).collect();
+    assert_eq!(sources, ["in subcommand show", "in `--id`"]);
+    assert_eq!(Error::no_value().sources().count(), 0);
+}
+
+#[test]
+fn test_custom() {
+    let e = Error::custom("port 0 is reserved")
+        .chain(ErrorInner::InArgument("--port".into()));
+    assert_eq!(e.to_string(), "port 0 is reserved");
+    assert_eq!(e.sources().next().unwrap().to_string(), "in `--port`");
+    assert_eq!(
+        Error::custom("port 0 is reserved").to_json(),
+        r#"{"kind":"custom","message":"port 0 is reserved"}"#
+    );
+}
+
+#[test]
+fn test_conflicting_arguments() {
+    let e = Error::conflicting_arguments("--verbose", "--quiet");
+    assert_eq!(e.to_string(), "argument --verbose cannot be used with --quiet");
+    assert_eq!(
+        e.inner(),
+        &ErrorInner::ConflictingArguments { a: "--verbose".into(), b: "--quiet".into() }
+    );
+}
+
+#[test]
+fn test_to_json() {
+    let e = Error::unexpected_value(
+        "x\"y",
+        Some(PossibleValues::OneOf(vec![
+            PossibleValues::String("a".into()),
+            PossibleValues::Other("number".into()),
+        ])),
+    )
+    .chain(ErrorInner::InArgument("--foo".into()));
+    assert_eq!(
+        e.to_json(),
+        r#"{"kind":"invalid_value","message":"unexpected value `x\\\"y`, expected `a` or number","got":"x\"y","expected":["a","number"],"source":{"kind":"in_argument","message":"in `--foo`","flag":"--foo"}}"#
+    );
+}
+
+#[test]
+fn test_kind_eq() {
+    let e = Error::missing_value().chain(ErrorInner::InArgument("--out".into()));
+    assert!(e.kind_eq(&ErrorInner::MissingValue));
+    assert!(!e.kind_eq(&ErrorInner::NoValue));
+    assert!(!e.kind_eq(&ErrorInner::InArgument("--out".into())));
+}
+
+#[test]
+fn test_requires_value() {
+    let e = Error::requires_value(&Flag::LongShort("out", "o"));
+    assert_eq!(e.to_string(), "argument --out requires a value");
+    assert_eq!(
+        e.to_json(),
+        r#"{"kind":"requires_value","message":"argument --out requires a value","flag":"--out"}"#
+    );
+}
+
+#[test]
+fn test_message_only() {
+    let e = Error::missing_value()
+        .chain(ErrorInner::InArgument("--out".into()))
+        .chain(ErrorInner::InSubcommand("build".into()));
+    assert_eq!(e.message_only(), "missing value");
+}
@@ -2,7 +2,7 @@ use palex::ArgsInput;
 
 use crate::help::PossibleValues;
 use crate::util::{ArgCtx, Flag};
-use crate::{Error, ErrorInner, Parse};
+use crate::{Error, Parse};
 
 /// Trait for extracting information from the command-line input. This is
 /// implemented for flags, positional and named arguments, subcommands, etc.
@@ -85,6 +85,55 @@ pub trait FromInput<'a>: Sized {
     }
 }
 
+/// Trait for a group of arguments that can be parsed inline as part of another
+/// struct, e.g. options that are shared by several subcommands.
+///
+/// This is implemented by the [`FromInput`](derive@crate::FromInput) derive
+/// macro for structs with the `parkour(group)` attribute. The group is used by
+/// adding a field with the `arg(flatten)` attribute:
+///
+/// ```
+/// use parkour::prelude::*;
+///
+/// #[derive(FromInput)]
+/// #[parkour(group)]
+/// struct CommonOpts {
+///     #[arg(long, short)]
+///     verbose: bool,
+/// }
+///
+/// #[derive(FromInput)]
+/// #[parkour(main)]
+/// struct Command {
+///     #[arg(flatten)]
+///     common: CommonOpts,
+///     #[arg(long)]
+///     dry_run: bool,
+/// }
+/// ```
+pub trait ArgGroup: Sized {
+    /// The values of the arguments that were parsed so far
+    type State;
+
+    /// Returns the state before any arguments are parsed
+    fn init() -> Self::State;
+
+    /// Tries to parse one of the arguments in the group. Returns `true` if an
+    /// argument was consumed.
+    fn apply_arg(state: &mut Self::State, input: &mut ArgsInput) -> Result<bool, Error>;
+
+    /// Creates the value after all arguments were parsed. This fails if a
+    /// required argument is missing.
+    fn finish(state: Self::State) -> Result<Self, Error>;
+
+    /// Returns the entries of the group in the "Options" section of the help
+    /// message, e.g. `"  --verbose, -v"`. The derive macro includes them in
+    /// the help message of the struct the group is flattened into.
+    fn help_options() -> Vec<&'static str> {
+        Vec::new()
+    }
+}
+
 /// Trait for parsing a _value_. A value can be
 /// - a positional argument
 /// - a string following a flag; e.g in `--foo bar` or `--foo=bar`, the `bar`
@@ -108,7 +157,7 @@ pub trait FromInputValue<'a>: Sized {
     /// ```no_run
     /// # use parkour::prelude::*;
     /// let mut input = parkour::parser();
-    /// let n: i32 = input.parse_value(&NumberCtx { min: -1000, max: 1000 })?;
+    /// let n: i32 = input.parse_value(&NumberCtx::new(-1000, 1000))?;
     /// # Ok::<(), parkour::Error>(())
     /// ```
     fn from_input_value(value: &str, context: &Self::Context) -> Result<Self, Error>;
@@ -122,6 +171,19 @@ pub trait FromInputValue<'a>: Sized {
 
     /// Returns a list or short description of all the accepted values
     fn possible_values(context: &Self::Context) -> Option<PossibleValues>;
+
+    /// Parses the value(s) after a flag, e.g. `--foo bar`. This is invoked by
+    /// the [`FromInput`] implementation for values. The default
+    /// implementation parses a single value with [`Parse::parse_value`].
+    ///
+    /// Override this if the value can span multiple arguments, e.g. `--rgb 1
+    /// 2 3`. Return [`Error::no_value`] if no value is present.
+    fn from_input_after_flag(
+        input: &mut ArgsInput,
+        context: &Self::Context,
+    ) -> Result<Self, Error> {
+        input.parse_value(context)
+    }
 }
 
 impl<'a, T: FromInputValue<'a>> FromInput<'a> for T
@@ -132,10 +194,9 @@ where
 
     fn from_input(input: &mut ArgsInput, context: &Self::Context) -> Result<Self, Error> {
         if Flag::from_input(input, &context.flag)? {
-            match input.parse_value(&context.inner) {
+            match T::from_input_after_flag(input, &context.inner) {
                 Ok(value) => Ok(value),
-                Err(e) if e.is_no_value() => Err(Error::missing_value()
-                    .chain(ErrorInner::InArgument(context.flag.first_to_string()))),
+                Err(e) if e.is_no_value() => Err(Error::requires_value(&context.flag)),
                 Err(e) => Err(e),
             }
         } else {
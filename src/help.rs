@@ -31,6 +31,27 @@ pub enum PossibleValues {
     /// This variant allows nesting lists of possible values. When displaying
     /// them, they should be flattened automatically.
     OneOf(Vec<PossibleValues>),
+
+    /// A range of numbers. Unlike [`PossibleValues::Other`], this can be
+    /// understood by tools, e.g. for shell completions:
+    ///
+    /// ```
+    /// # use parkour::help::PossibleValues;
+    /// let range = PossibleValues::Range {
+    ///     kind: "integer".into(),
+    ///     min: "1".into(),
+    ///     max: "10".into(),
+    /// };
+    /// assert_eq!(range.to_string(), "integer between 1 and 10");
+    /// ```
+    Range {
+        /// The kind of number, e.g. `integer`
+        kind: String,
+        /// The smallest accepted number
+        min: String,
+        /// The largest accepted number
+        max: String,
+    },
 }
 
 /// This struct defines a possible value of a type representing a _value_.
@@ -45,6 +66,15 @@ pub enum PossibleValue<'a> {
     /// A string describing the kind of accepted values. For example,
     /// `Other("positive number")` means all positive numbers are accepted.
     Other(&'a str),
+    /// A range of numbers, see [`PossibleValues::Range`].
+    Range {
+        /// The kind of number, e.g. `integer`
+        kind: &'a str,
+        /// The smallest accepted number
+        min: &'a str,
+        /// The largest accepted number
+        max: &'a str,
+    },
 }
 
 impl PartialEq for PossibleValues {
@@ -53,7 +83,6 @@ impl PartialEq for PossibleValues {
 
         match (self, other) {
             (String(a), String(b)) | (Other(a), Other(b)) => a == b,
-            (String(_), Other(_)) | (Other(_), String(_)) => false,
             (_, _) => self.iter().eq(other.iter()),
         }
     }
@@ -63,7 +92,8 @@ impl Eq for PossibleValues {}
 
 impl fmt::Display for PossibleValues {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut iter = self.iter().peekable();
+        let values = self.dedup();
+        let mut iter = values.iter().peekable();
         match iter.next() {
             Some(v) => {
                 write!(f, "{}", v)?;
@@ -87,6 +117,9 @@ impl fmt::Display for PossibleValue<'_> {
         match *self {
             PossibleValue::String(s) => write!(f, "`{}`", s.escape_debug()),
             PossibleValue::Other(o) => f.write_str(o),
+            PossibleValue::Range { kind, min, max } => {
+                write!(f, "{} between {} and {}", kind, min, max)
+            }
         }
     }
 }
@@ -95,70 +128,75 @@ impl PossibleValues {
     /// Returns an iterator over all the possible values. This iterator flattens
     /// [`PossibleValues::OneOf`].
     pub fn iter(&self) -> PossibleValueIter<'_> {
-        PossibleValueIter { values: Some(self), index: 0, then: None }
+        PossibleValueIter { first: Some(self), stack: Vec::new() }
+    }
+
+    /// Returns all the possible values like [`PossibleValues::iter`], but
+    /// without duplicates. The order of the first occurrences is preserved.
+    ///
+    /// ```
+    /// # use parkour::help::{PossibleValue, PossibleValues};
+    /// let values = PossibleValues::OneOf(vec![
+    ///     PossibleValues::String("a".into()),
+    ///     PossibleValues::String("b".into()),
+    ///     PossibleValues::String("a".into()),
+    /// ]);
+    /// assert_eq!(values.dedup(), [PossibleValue::String("a"), PossibleValue::String("b")]);
+    /// assert_eq!(values.to_string(), "`a` or `b`");
+    /// ```
+    pub fn dedup(&self) -> Vec<PossibleValue<'_>> {
+        let mut values: Vec<PossibleValue<'_>> = Vec::new();
+        for value in self.iter() {
+            if !values.contains(&value) {
+                values.push(value);
+            }
+        }
+        values
     }
 }
 
 /// Iterator over possible values that flattens [`PossibleValues::OneOf`].
+///
+/// The iterator doesn't use recursion, so arbitrarily deeply nested values
+/// can't overflow the stack.
 pub struct PossibleValueIter<'a> {
-    values: Option<&'a PossibleValues>,
-    index: usize,
-    then: Option<Box<PossibleValueIter<'a>>>,
+    first: Option<&'a PossibleValues>,
+    stack: Vec<std::slice::Iter<'a, PossibleValues>>,
 }
 
 impl<'a> Iterator for PossibleValueIter<'a> {
     type Item = PossibleValue<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.values {
-            Some(PossibleValues::String(s)) => {
-                advance(self);
-                Some(PossibleValue::String(s))
-            }
-            Some(PossibleValues::Other(o)) => {
-                advance(self);
-                Some(PossibleValue::Other(o))
-            }
-
-            Some(PossibleValues::OneOf(o)) => {
-                let next = &o[self.index];
-                if self.index + 1 >= o.len() {
-                    advance(self);
-                } else {
-                    self.index += 1;
+        loop {
+            let value = match self.first.take() {
+                Some(value) => value,
+                None => match self.stack.last_mut()?.next() {
+                    Some(value) => value,
+                    None => {
+                        self.stack.pop();
+                        continue;
+                    }
+                },
+            };
+
+            return Some(match value {
+                PossibleValues::String(s) => PossibleValue::String(s),
+                PossibleValues::Other(o) => PossibleValue::Other(o),
+                PossibleValues::Range { kind, min, max } => {
+                    PossibleValue::Range { kind, min, max }
                 }
-
-                let then = std::mem::replace(
-                    self,
-                    PossibleValueIter { values: Some(next), index: 0, then: None },
-                );
-                self.then = Some(Box::new(then));
-                self.next()
-            }
-            None => None,
-        }
-    }
-
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        match self.values {
-            Some(PossibleValues::String(_)) => (1, Some(1)),
-            Some(PossibleValues::Other(_)) => (1, Some(1)),
-            Some(PossibleValues::OneOf(v)) => (v.len(), None),
-            None => (0, None),
+                PossibleValues::OneOf(o) => {
+                    self.stack.push(o.iter());
+                    continue;
+                }
+            });
         }
     }
 }
 
 impl FusedIterator for PossibleValueIter<'_> {}
 
-fn advance(iter: &mut PossibleValueIter) {
-    if let Some(then) = iter.then.take() {
-        *iter = *then;
-    } else {
-        iter.values = None;
-    }
-}
-
 #[test]
 fn test_values_iterator() {
     use PossibleValues::*;
@@ -191,3 +229,16 @@ fn test_values_iterator() {
         ]
     );
 }
+
+#[test]
+fn test_deeply_nested_values() {
+    use PossibleValues::*;
+
+    let mut values = String("x".into());
+    for _ in 0..10_000 {
+        values = OneOf(vec![OneOf(vec![]), values]);
+    }
+    assert_eq!(values.iter().count(), 1);
+    assert_eq!(values.to_string(), "`x`");
+    assert_eq!(OneOf(vec![]).to_string(), "nothing");
+}
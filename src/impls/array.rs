@@ -1,23 +1,41 @@
 use std::convert::TryInto;
 
+use palex::ArgsInput;
+
 use crate::help::PossibleValues;
-use crate::{Error, ErrorInner, FromInputValue};
+use crate::{Error, ErrorInner, FromInputValue, Parse};
 
+/// The parsing context for arrays. Arrays can be parsed from the following
+/// syntaxes:
+///
+/// 1. `-f=a,b,c`
+/// 2. `-f a,b,c`
+/// 3. `-f a b c` (only if `greedy` is `true`)
+///
+/// The number of values must be equal to the length of the array.
 #[derive(Debug)]
 pub struct ArrayCtx<C> {
+    /// The delimiter that separates the values, when they are in the same
+    /// argument. The default is a comma.
     pub delimiter: Option<char>,
+    /// The context of the values we want to parse
     pub inner: C,
+    /// When `greedy` is set to true, the values may also be separated by
+    /// whitespace, e.g. `--rgb 1 2 3`. This only applies when the values
+    /// aren't attached to the flag with `=`. This defaults to `false`.
+    pub greedy: bool,
 }
 
 impl<C> ArrayCtx<C> {
+    /// Creates a new `ArrayCtx` instance, which isn't greedy
     pub fn new(delimiter: Option<char>, inner: C) -> Self {
-        Self { delimiter, inner }
+        Self { delimiter, inner, greedy: false }
     }
 }
 
 impl<C: Default> Default for ArrayCtx<C> {
     fn default() -> Self {
-        ArrayCtx { delimiter: Some(','), inner: C::default() }
+        ArrayCtx { delimiter: Some(','), inner: C::default(), greedy: false }
     }
 }
 
@@ -28,22 +46,56 @@ impl<'a, T: FromInputValue<'a>, const N: usize> FromInputValue<'a> for [T; N] {
         if let Some(delim) = context.delimiter {
             let values = value
                 .split(delim)
-                .map(|s| T::from_input_value(s, &context.inner))
+                .enumerate()
+                .map(|(i, s)| {
+                    T::from_input_value(s, &context.inner)
+                        .map_err(|e| e.chain(ErrorInner::IncompleteValue(i)))
+                })
                 .collect::<Result<Vec<T>, _>>()?;
 
-            let len = values.len();
-            match values.try_into() {
-                Ok(values) => Ok(values),
-                Err(_) => {
-                    Err(ErrorInner::WrongNumberOfValues { expected: N, got: len }.into())
-                }
-            }
+            into_array(values)
         } else {
             Err(ErrorInner::WrongNumberOfValues { expected: N, got: 1 }.into())
         }
     }
 
+    fn from_input_after_flag(
+        input: &mut ArgsInput,
+        context: &Self::Context,
+    ) -> Result<Self, Error> {
+        if !context.greedy || input.can_parse_value_no_whitespace() {
+            return input.parse_value(context);
+        }
+        if let Some(delim) = context.delimiter {
+            if input.value().is_some_and(|v| v.as_str().contains(delim)) {
+                return input.parse_value(context);
+            }
+        }
+
+        let mut values = Vec::with_capacity(N);
+        for i in 0..N {
+            match input.parse_value(&context.inner) {
+                Ok(value) => values.push(value),
+                Err(e) if e.is_no_value() && i > 0 => {
+                    return Err(
+                        ErrorInner::WrongNumberOfValues { expected: N, got: i }.into()
+                    );
+                }
+                Err(e) => return Err(e.chain(ErrorInner::IncompleteValue(i))),
+            }
+        }
+        into_array(values)
+    }
+
     fn possible_values(context: &Self::Context) -> Option<PossibleValues> {
         T::possible_values(&context.inner)
     }
 }
+
+fn into_array<T, const N: usize>(values: Vec<T>) -> Result<[T; N], Error> {
+    let len = values.len();
+    match values.try_into() {
+        Ok(values) => Ok(values),
+        Err(_) => Err(ErrorInner::WrongNumberOfValues { expected: N, got: len }.into()),
+    }
+}
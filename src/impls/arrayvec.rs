@@ -0,0 +1,26 @@
+use ::arrayvec::ArrayVec;
+use palex::ArgsInput;
+
+use super::list::parse_list;
+use super::ListCtx;
+use crate::{ErrorInner, FromInput, FromInputValue, Result};
+
+/// An `ArrayVec` is parsed like a [`Vec`], but it can contain at most `N`
+/// values. If more values are provided, a [`ErrorInner::TooManyValues`] error
+/// is returned.
+impl<'a, T, C: 'a, const N: usize> FromInput<'a> for ArrayVec<T, N>
+where
+    T: FromInputValue<'a, Context = C>,
+{
+    type Context = ListCtx<'a, C>;
+
+    fn from_input(input: &mut ArgsInput, context: &Self::Context) -> Result<Self> {
+        let values = parse_list::<Vec<T>, T>(input, context)?;
+        let count = values.len();
+        if count <= N {
+            Ok(values.into_iter().collect())
+        } else {
+            Err(ErrorInner::TooManyValues { max: N, count }.into())
+        }
+    }
+}
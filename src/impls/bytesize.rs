@@ -0,0 +1,72 @@
+use crate::help::PossibleValues;
+use crate::{Error, FromInputValue};
+
+/// A number of bytes, which can have a SI suffix (`KB`, `MB`, `GB`, `TB`,
+/// `PB`) or a binary suffix (`KiB`, `MiB`, `GiB`, `TiB`, `PiB`), e.g. `512MiB`
+/// is parsed as `ByteSize(536870912)`. Suffixes are case-insensitive, and the
+/// `B` suffix or no suffix at all means bytes.
+///
+/// ### Example
+///
+/// ```no_run
+/// # use parkour::prelude::*;
+/// use parkour::impls::ByteSize;
+///
+/// # let mut input: parkour::ArgsInput = todo!();
+/// let cache: ByteSize = input.parse_value(&())?;
+/// # Ok::<(), parkour::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ByteSize(pub u64);
+
+impl ByteSize {
+    /// Returns the number of bytes
+    pub fn bytes(self) -> u64 {
+        self.0
+    }
+}
+
+const UNITS: &[(&str, u64)] = &[
+    ("", 1),
+    ("b", 1),
+    ("kb", 1_000),
+    ("mb", 1_000_000),
+    ("gb", 1_000_000_000),
+    ("tb", 1_000_000_000_000),
+    ("pb", 1_000_000_000_000_000),
+    ("kib", 1 << 10),
+    ("mib", 1 << 20),
+    ("gib", 1 << 30),
+    ("tib", 1 << 40),
+    ("pib", 1 << 50),
+];
+
+impl FromInputValue<'static> for ByteSize {
+    type Context = ();
+
+    fn from_input_value(value: &str, context: &()) -> Result<Self, Error> {
+        let split = value.find(|c: char| !c.is_ascii_digit()).unwrap_or(value.len());
+        let (number, unit) = value.split_at(split);
+
+        let factor = UNITS
+            .iter()
+            .find(|&&(u, _)| unit.eq_ignore_ascii_case(u))
+            .map(|&(_, factor)| factor);
+
+        match (number.parse::<u64>(), factor) {
+            (Ok(n), Some(factor)) => {
+                n.checked_mul(factor).map(ByteSize).ok_or_else(|| {
+                    Error::unexpected_value(
+                        format!("size {}", value),
+                        Self::possible_values(context),
+                    )
+                })
+            }
+            _ => Err(Error::unexpected_value(value, Self::possible_values(context))),
+        }
+    }
+
+    fn possible_values(_: &()) -> Option<PossibleValues> {
+        Some(PossibleValues::Other("size in bytes, e.g. 512MiB or 2GB".into()))
+    }
+}
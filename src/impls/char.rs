@@ -1,3 +1,5 @@
+use std::iter::FromIterator;
+
 use crate::help::PossibleValues;
 use crate::{Error, FromInputValue};
 
@@ -20,3 +22,58 @@ impl FromInputValue<'static> for char {
         Some(PossibleValues::Other("character".into()))
     }
 }
+
+/// A collection of characters, where each character of the value is a separate
+/// item, e.g. `rwx` is parsed as `['r', 'w', 'x']`. `C` can be any collection
+/// that implements [`FromIterator<char>`], e.g. `Vec<char>` or
+/// `HashSet<char>`.
+///
+/// ### Example
+///
+/// ```no_run
+/// # use parkour::prelude::*;
+/// use parkour::impls::{Chars, CharsCtx};
+///
+/// # let mut input: parkour::ArgsInput = todo!();
+/// let ctx = CharsCtx { allowed: Some("rwx") };
+/// let perms: Chars<Vec<char>> = input.parse_value(&ctx)?;
+/// # Ok::<(), parkour::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Chars<C>(pub C);
+
+impl<C> Chars<C> {
+    /// Returns the inner collection
+    pub fn into_inner(self) -> C {
+        self.0
+    }
+}
+
+/// The parsing context for [`Chars`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CharsCtx<'a> {
+    /// The characters that are accepted. The default is `None`, which means
+    /// that all characters are accepted.
+    pub allowed: Option<&'a str>,
+}
+
+impl<'a, C: FromIterator<char>> FromInputValue<'a> for Chars<C> {
+    type Context = CharsCtx<'a>;
+
+    fn from_input_value(value: &str, context: &CharsCtx<'a>) -> Result<Self, Error> {
+        if let Some(allowed) = context.allowed {
+            if let Some(c) = value.chars().find(|&c| !allowed.contains(c)) {
+                return Err(Error::unexpected_value(c, Self::possible_values(context)));
+            }
+        }
+        Ok(Chars(value.chars().collect()))
+    }
+
+    fn possible_values(context: &CharsCtx<'a>) -> Option<PossibleValues> {
+        context.allowed.map(|allowed| {
+            PossibleValues::OneOf(
+                allowed.chars().map(|c| PossibleValues::String(c.to_string())).collect(),
+            )
+        })
+    }
+}
@@ -0,0 +1,73 @@
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+
+use crate::help::PossibleValues;
+use crate::impls::NumberCtx;
+use crate::{Error, FromInputValue};
+
+impl Default for NumberCtx<Decimal> {
+    fn default() -> Self {
+        NumberCtx::new(Decimal::MIN, Decimal::MAX)
+    }
+}
+
+impl FromInputValue<'static> for Decimal {
+    type Context = NumberCtx<Decimal>;
+
+    fn from_input_value(value: &str, context: &Self::Context) -> Result<Self, Error> {
+        let n = Decimal::from_str(value).map_err(|e| {
+            Error::unexpected_value(value, Self::possible_values(context)).with_source(e)
+        })?;
+        if n >= context.min && n <= context.max {
+            Ok(n)
+        } else {
+            Err(Error::unexpected_value(value, Self::possible_values(context)))
+        }
+    }
+
+    fn allow_leading_dashes(context: &Self::Context) -> bool {
+        context.min.is_sign_negative()
+    }
+
+    fn possible_values(context: &Self::Context) -> Option<PossibleValues> {
+        Some(match (context.min, context.max) {
+            (min, max) if min == Decimal::MIN && max == Decimal::MAX => {
+                PossibleValues::Other("decimal number".into())
+            }
+            (min, max) if min == Decimal::MIN => {
+                PossibleValues::Other(format!("decimal number at most {}", max))
+            }
+            (min, max) if max == Decimal::MAX => {
+                PossibleValues::Other(format!("decimal number at least {}", min))
+            }
+            (min, max) => PossibleValues::Range {
+                kind: "decimal number".into(),
+                min: min.to_string(),
+                max: max.to_string(),
+            },
+        })
+    }
+}
+
+#[test]
+fn test_decimal() {
+    let price = Decimal::from_input_value("19.99", &Default::default()).unwrap();
+    assert_eq!(price, Decimal::new(1999, 2));
+    assert_eq!(price.to_string(), "19.99");
+
+    let e = Decimal::from_input_value("19.9x", &Default::default()).unwrap_err();
+    assert_eq!(e.to_string(), "unexpected value `19.9x`, expected decimal number");
+
+    let ctx = NumberCtx::new(Decimal::ZERO, Decimal::ONE_HUNDRED);
+    let e = Decimal::from_input_value("-0.5", &ctx).unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "unexpected value `-0.5`, expected decimal number between 0 and 100"
+    );
+    let e = Decimal::from_input_value("x", &ctx).unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "unexpected value `x`, expected decimal number between 0 and 100"
+    );
+}
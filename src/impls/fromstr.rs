@@ -0,0 +1,63 @@
+use std::str::FromStr;
+
+use crate::help::PossibleValues;
+use crate::{Error, FromInputValue};
+
+/// A wrapper that parses any type implementing [`FromStr`]. This is useful for
+/// types from other crates, which can't implement [`FromInputValue`] because
+/// of the orphan rule. If parsing fails, the `FromStr` error is attached as
+/// the source of the error.
+///
+/// ### Example
+///
+/// ```no_run
+/// # use parkour::prelude::*;
+/// use parkour::impls::FromStrValue;
+/// use std::num::NonZeroU32;
+///
+/// # let mut input: parkour::ArgsInput = todo!();
+/// let FromStrValue(jobs) = input.parse_value::<FromStrValue<NonZeroU32>>(&())?;
+/// # Ok::<(), parkour::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct FromStrValue<T>(pub T);
+
+impl<T> FromStrValue<T> {
+    /// Returns the parsed value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> FromInputValue<'static> for FromStrValue<T>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    type Context = ();
+
+    fn from_input_value(value: &str, _: &()) -> Result<Self, Error> {
+        value
+            .parse()
+            .map(FromStrValue)
+            .map_err(|e| Error::unexpected_value(value, None).with_source(e))
+    }
+
+    fn possible_values(_: &()) -> Option<PossibleValues> {
+        None
+    }
+}
+
+#[test]
+fn test_from_str_value() {
+    use std::error::Error as _;
+    use std::net::Ipv4Addr;
+
+    let FromStrValue(ip) =
+        FromStrValue::<Ipv4Addr>::from_input_value("10.0.0.1", &()).unwrap();
+    assert_eq!(ip, Ipv4Addr::new(10, 0, 0, 1));
+
+    let e = FromStrValue::<Ipv4Addr>::from_input_value("10.0.0", &()).unwrap_err();
+    assert_eq!(e.to_string(), "unexpected value `10.0.0`");
+    assert_eq!(e.source().unwrap().to_string(), "invalid IPv4 address syntax");
+}
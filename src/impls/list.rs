@@ -1,6 +1,7 @@
-use std::collections::{BTreeSet, HashSet, LinkedList, VecDeque};
+use std::collections::{BTreeSet, HashMap, HashSet, LinkedList, VecDeque};
 use std::hash::Hash;
 use std::iter::FromIterator;
+use std::rc::Rc;
 
 use palex::ArgsInput;
 
@@ -18,6 +19,10 @@ use super::StringCtx;
 /// - [`std::collections::HashSet`]
 /// - [`std::collections::BTreeSet`]
 /// - [`std::collections::LinkedList`]
+/// - `Box<[T]>` and `Rc<[T]>`
+/// - [`std::collections::HashMap`], whose entries are parsed as tuples, e.g.
+///   `-e A=1,B=2` with a [`TupleCtx`](super::TupleCtx) that uses `=` as
+///   delimiter
 ///
 /// This can parse argument lists like the following:
 ///
@@ -25,8 +30,9 @@ use super::StringCtx;
 /// 2. `-f=a,b,c,d`
 /// 3. `-f a -f b -f c -f d`
 ///
-/// If you want to allow the third syntax, use [`crate::actions::Append`]
-/// action, to make sure that all values are saved.
+/// Which of the first two syntaxes is accepted is configured with the
+/// [`Separator`]. If you want to allow the third syntax, use
+/// [`crate::actions::Append`] action, to make sure that all values are saved.
 #[derive(Debug)]
 pub struct ListCtx<'a, C> {
     /// The flag after which the values should be parsed.
@@ -34,20 +40,46 @@ pub struct ListCtx<'a, C> {
     /// The maximum number of items that can be parsed at once. The default is
     /// `usize::MAX`.
     pub max_items: usize,
-    /// The delimiter that is used when the `-f=a,b,c,d` syntax is used. The
-    /// default is a comma.
-    pub delimiter: Option<char>,
+    /// The maximum total length in bytes of the values that can be parsed at
+    /// once. Values beyond this limit are rejected before they are parsed,
+    /// which protects against excessively large inputs. The default is
+    /// `usize::MAX`.
+    pub max_bytes: usize,
+    /// How the values are separated. The default is [`Separator::Comma`].
+    pub separator: Separator,
+    /// An argument that ends the list when the values are separated by
+    /// whitespace, e.g. `end` or `--`. The terminator is consumed, but not
+    /// included in the list. This is useful when the values might look like
+    /// flags. The default is `None`.
+    pub terminator: Option<&'a str>,
     /// The context of the values we want to parse
     pub inner: C,
-    /// When `greedy` is set to true, the parser will greedily try to parse as
-    /// many values as possible (up to `max_items`) at once, except when the
-    /// 2nd syntax is used. This defaults to `false`, so the 1st syntax is
-    /// unavailable by default.
+}
+
+/// Specifies how the values of a list are separated. See [`ListCtx`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Separator {
+    /// The values are in a single argument, separated by commas, e.g. `-f
+    /// a,b,c` or `-f=a,b,c`. This is the default.
+    #[default]
+    Comma,
+    /// The values are in separate arguments, e.g. `-f a b c`. The parser
+    /// greedily consumes as many values as possible (up to
+    /// [`ListCtx::max_items`]). When the value is attached to the flag, e.g.
+    /// `-f=a`, only one value is parsed.
     ///
-    /// Note that setting `greedy` to `true` is less problematic if the values
-    /// can't start with a dash, because then it will stop consuming arguments
-    /// as soon as it encounters an argument starting with a dash.
-    pub greedy: bool,
+    /// Note that this is less problematic if the values can't start with a
+    /// dash, because then it will stop consuming arguments as soon as it
+    /// encounters an argument starting with a dash.
+    Whitespace,
+    /// Accepts both [`Separator::Comma`] and [`Separator::Whitespace`]. The
+    /// values are split at commas if they are attached to the flag (`-f=a,b`),
+    /// or if the first value contains a comma (`-f a,b`). Otherwise they are
+    /// parsed greedily like with [`Separator::Whitespace`].
+    Either,
+    /// The values are in a single argument, separated by the given character,
+    /// e.g. `-f a:b:c` or `-f=a:b:c`
+    Custom(char),
 }
 
 impl<'a, C: Default> From<Flag<'a>> for ListCtx<'a, C> {
@@ -55,9 +87,10 @@ impl<'a, C: Default> From<Flag<'a>> for ListCtx<'a, C> {
         ListCtx {
             flag,
             max_items: usize::MAX,
-            delimiter: Some(','),
+            max_bytes: usize::MAX,
+            separator: Separator::Comma,
+            terminator: None,
             inner: C::default(),
-            greedy: false,
         }
     }
 }
@@ -69,18 +102,7 @@ where
     type Context = ListCtx<'a, C>;
 
     fn from_input(input: &mut ArgsInput, context: &Self::Context) -> Result<Self> {
-        let mut flag_set = false;
-        Set(&mut flag_set).apply(input, &context.flag)?;
-
-        if flag_set {
-            if input.can_parse_value_no_whitespace() || context.delimiter.is_some() {
-                parse_list_no_ws(input, context)
-            } else {
-                parse_list_with_ws(input, context)
-            }
-        } else {
-            Err(Error::no_value())
-        }
+        parse_list(input, context)
     }
 }
 
@@ -91,18 +113,7 @@ where
     type Context = ListCtx<'a, C>;
 
     fn from_input(input: &mut ArgsInput, context: &Self::Context) -> Result<Self> {
-        let mut flag_set = false;
-        Set(&mut flag_set).apply(input, &context.flag)?;
-
-        if flag_set {
-            if input.can_parse_value_no_whitespace() || context.delimiter.is_some() {
-                parse_list_no_ws(input, context)
-            } else {
-                parse_list_with_ws(input, context)
-            }
-        } else {
-            Err(Error::no_value())
-        }
+        parse_list(input, context)
     }
 }
 
@@ -113,18 +124,7 @@ where
     type Context = ListCtx<'a, C>;
 
     fn from_input(input: &mut ArgsInput, context: &Self::Context) -> Result<Self> {
-        let mut flag_set = false;
-        Set(&mut flag_set).apply(input, &context.flag)?;
-
-        if flag_set {
-            if input.can_parse_value_no_whitespace() || context.delimiter.is_some() {
-                parse_list_no_ws(input, context)
-            } else {
-                parse_list_with_ws(input, context)
-            }
-        } else {
-            Err(Error::no_value())
-        }
+        parse_list(input, context)
     }
 }
 
@@ -135,18 +135,7 @@ where
     type Context = ListCtx<'a, C>;
 
     fn from_input(input: &mut ArgsInput, context: &Self::Context) -> Result<Self> {
-        let mut flag_set = false;
-        Set(&mut flag_set).apply(input, &context.flag)?;
-
-        if flag_set {
-            if input.can_parse_value_no_whitespace() || context.delimiter.is_some() {
-                parse_list_no_ws(input, context)
-            } else {
-                parse_list_with_ws(input, context)
-            }
-        } else {
-            Err(Error::no_value())
-        }
+        parse_list(input, context)
     }
 }
 
@@ -157,32 +146,117 @@ where
     type Context = ListCtx<'a, C>;
 
     fn from_input(input: &mut ArgsInput, context: &Self::Context) -> Result<Self> {
-        let mut flag_set = false;
-        Set(&mut flag_set).apply(input, &context.flag)?;
+        parse_list(input, context)
+    }
+}
+
+impl<'a, K, V, C: 'a> FromInput<'a> for HashMap<K, V>
+where
+    (K, V): FromInputValue<'a, Context = C>,
+    K: Hash + Eq,
+{
+    type Context = ListCtx<'a, C>;
+
+    fn from_input(input: &mut ArgsInput, context: &Self::Context) -> Result<Self> {
+        parse_list(input, context)
+    }
+}
+
+impl<'a, T, C: 'a> FromInput<'a> for Box<[T]>
+where
+    T: FromInputValue<'a, Context = C>,
+{
+    type Context = ListCtx<'a, C>;
+
+    fn from_input(input: &mut ArgsInput, context: &Self::Context) -> Result<Self> {
+        parse_list::<Vec<T>, T>(input, context).map(Vec::into_boxed_slice)
+    }
+}
 
-        if flag_set {
-            if input.can_parse_value_no_whitespace() || context.delimiter.is_some() {
-                parse_list_no_ws(input, context)
+impl<'a, T, C: 'a> FromInput<'a> for Rc<[T]>
+where
+    T: FromInputValue<'a, Context = C>,
+{
+    type Context = ListCtx<'a, C>;
+
+    fn from_input(input: &mut ArgsInput, context: &Self::Context) -> Result<Self> {
+        parse_list::<Vec<T>, T>(input, context).map(Rc::from)
+    }
+}
+
+pub(super) fn parse_list<'a, L: List<T>, T: FromInputValue<'a>>(
+    input: &mut ArgsInput,
+    context: &ListCtx<'a, T::Context>,
+) -> Result<L> {
+    let mut flag_set = false;
+    Set(&mut flag_set).apply(input, &context.flag)?;
+    if !flag_set {
+        return Err(Error::no_value());
+    }
+
+    let result = match context.separator {
+        Separator::Comma => parse_list_no_ws(input, context, Some(',')),
+        Separator::Custom(delim) => parse_list_no_ws(input, context, Some(delim)),
+        Separator::Whitespace => {
+            if input.can_parse_value_no_whitespace() {
+                parse_list_no_ws(input, context, None)
             } else {
                 parse_list_with_ws(input, context)
             }
-        } else {
-            Err(Error::no_value())
         }
+        Separator::Either => {
+            let allow_dashes = T::allow_leading_dashes(&context.inner);
+            if input.can_parse_value_no_whitespace()
+                || next_value_contains(input, ',', allow_dashes)
+            {
+                parse_list_no_ws(input, context, Some(','))
+            } else {
+                parse_list_with_ws(input, context)
+            }
+        }
+    };
+    // the context of the flag is added by the action, e.g. `Append`
+    match result {
+        Err(e) if e.is_no_value() => Err(Error::requires_value(&context.flag)),
+        result => result,
+    }
+}
+
+fn next_value_contains(input: &mut ArgsInput, delim: char, allow_dashes: bool) -> bool {
+    if allow_dashes {
+        input.value_allows_leading_dashes().is_some_and(|v| v.as_str().contains(delim))
+    } else {
+        input.value().is_some_and(|v| v.as_str().contains(delim))
+    }
+}
+
+fn next_value_len(input: &mut ArgsInput, allow_dashes: bool) -> Option<usize> {
+    if allow_dashes {
+        input.value_allows_leading_dashes().map(|v| v.as_str().len())
+    } else {
+        input.value().map(|v| v.as_str().len())
     }
 }
 
 fn parse_list_no_ws<'a, L: List<T>, T: FromInputValue<'a>>(
     input: &mut ArgsInput,
     context: &ListCtx<'a, T::Context>,
+    delimiter: Option<char>,
 ) -> Result<L> {
     let inner = &context.inner;
 
     let value: String = input.parse_value(
         &StringCtx::default().allow_leading_dashes(T::allow_leading_dashes(inner)),
     )?;
+    if value.len() > context.max_bytes {
+        return Err(ErrorInner::TooManyBytes {
+            max: context.max_bytes,
+            count: value.len(),
+        }
+        .into());
+    }
 
-    if let Some(delim) = context.delimiter {
+    if let Some(delim) = delimiter {
         let values: L = value
             .split(delim)
             .map(|s| T::from_input_value(s, inner))
@@ -208,13 +282,39 @@ fn parse_list_with_ws<'a, L: List<T>, T: FromInputValue<'a>>(
     input: &mut ArgsInput,
     context: &ListCtx<'a, T::Context>,
 ) -> Result<L> {
+    let is_terminator = |input: &mut ArgsInput| match context.terminator {
+        Some(t) => input.eat_value_allows_leading_dashes(t).is_some(),
+        None => false,
+    };
+
+    // the values are checked before they are parsed
+    let allow_dashes = T::allow_leading_dashes(&context.inner);
+    let mut bytes = 0;
+    let mut check_bytes = |input: &mut ArgsInput| -> Result<()> {
+        bytes += next_value_len(input, allow_dashes).unwrap_or(0);
+        if bytes > context.max_bytes {
+            Err(ErrorInner::TooManyBytes { max: context.max_bytes, count: bytes }.into())
+        } else {
+            Ok(())
+        }
+    };
+
+    let mut list = L::default();
+    if is_terminator(input) {
+        return Ok(list);
+    }
+
+    check_bytes(input)?;
     let first = input
         .parse_value(&context.inner)
         .map_err(|e| e.chain(ErrorInner::IncompleteValue(0)))?;
-    let mut list = L::default();
     list.add(first);
 
     for i in 1..context.max_items {
+        if is_terminator(input) {
+            break;
+        }
+        check_bytes(input)?;
         if let Some(value) = input
             .try_parse_value(&context.inner)
             .map_err(|e| e.chain(ErrorInner::IncompleteValue(i)))?
@@ -228,7 +328,7 @@ fn parse_list_with_ws<'a, L: List<T>, T: FromInputValue<'a>>(
     Ok(list)
 }
 
-trait List<T>: Default + FromIterator<T> {
+pub(super) trait List<T>: Default + FromIterator<T> {
     fn add(&mut self, value: T);
     fn len(&self) -> usize;
 }
@@ -282,3 +382,13 @@ impl<T: Hash + Eq> List<T> for HashSet<T> {
         self.len()
     }
 }
+
+impl<K: Hash + Eq, V> List<(K, V)> for HashMap<K, V> {
+    fn add(&mut self, (key, value): (K, V)) {
+        self.insert(key, value);
+    }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
@@ -2,14 +2,42 @@
 //! [`crate::FromInputValue`] traits.
 
 mod array;
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
 mod bool;
+mod bytesize;
 mod char;
+#[cfg(feature = "decimal")]
+mod decimal;
+mod fromstr;
 mod list;
+mod net;
 mod numbers;
+mod pathlist;
+mod percent;
+mod range;
+mod result;
+#[cfg(feature = "semver")]
+mod semver;
 mod string;
+mod time;
+mod toggle;
 mod tuple;
+mod validated;
 mod wrappers;
 
-pub use list::ListCtx;
+pub use self::char::{Chars, CharsCtx};
+pub use array::ArrayCtx;
+pub use bytesize::ByteSize;
+pub use fromstr::FromStrValue;
+pub use list::{ListCtx, Separator};
+pub use net::SocketAddrCtx;
 pub use numbers::NumberCtx;
+pub use pathlist::{PathList, PathListCtx};
+pub use percent::{Percent, PercentCtx};
+pub use range::ParsedRange;
 pub use string::StringCtx;
+pub use time::TimestampCtx;
+pub use toggle::{EnableDisable, Toggle};
+pub use tuple::TupleCtx;
+pub use validated::{Validated, ValidatedCtx};
@@ -0,0 +1,47 @@
+use std::net::{IpAddr, SocketAddr};
+
+use crate::help::PossibleValues;
+use crate::{Error, FromInputValue};
+
+/// The parsing context for socket addresses
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SocketAddrCtx {
+    /// The port that is used if the value is an IP address without a port,
+    /// e.g. `127.0.0.1` or `[::1]`. The default is `None`, which means that
+    /// the port is required.
+    pub default_port: Option<u16>,
+}
+
+impl SocketAddrCtx {
+    /// Creates a new `SocketAddrCtx` with a default port
+    pub fn with_default_port(port: u16) -> Self {
+        SocketAddrCtx { default_port: Some(port) }
+    }
+}
+
+impl FromInputValue<'static> for SocketAddr {
+    type Context = SocketAddrCtx;
+
+    fn from_input_value(value: &str, context: &SocketAddrCtx) -> Result<Self, Error> {
+        if let Ok(addr) = value.parse() {
+            return Ok(addr);
+        }
+        if let Some(port) = context.default_port {
+            let ip = match value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+                Some(ipv6) => ipv6,
+                None => value,
+            };
+            if let Ok(ip) = ip.parse::<IpAddr>() {
+                return Ok(SocketAddr::new(ip, port));
+            }
+        }
+        Err(Error::unexpected_value(value, Self::possible_values(context)))
+    }
+
+    fn possible_values(context: &SocketAddrCtx) -> Option<PossibleValues> {
+        Some(PossibleValues::Other(match context.default_port {
+            Some(_) => "socket address or IP address".into(),
+            None => "socket address".into(),
+        }))
+    }
+}
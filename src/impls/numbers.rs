@@ -1,22 +1,66 @@
 use std::num::*;
+use std::str::FromStr;
 
 use crate::help::PossibleValues;
 use crate::{Error, FromInputValue};
 
 /// The parsing context for numeric types.
+///
+/// Prefer [`NumberCtx::new`] or `NumberCtx { min, max, ..Default::default() }`
+/// over a struct literal that specifies all fields, so your code keeps working
+/// when fields are added.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct NumberCtx<T> {
     /// The smallest accepted number
     pub min: T,
     /// The largest accepted number
     pub max: T,
+    /// Whether only finite numbers are accepted. When this is `true` (the
+    /// default), `NaN` and infinity are rejected. Otherwise they are accepted
+    /// regardless of `min` and `max`. This has no effect on integers.
+    pub finite: bool,
+}
+
+impl<T> NumberCtx<T> {
+    /// Creates a new `NumberCtx` that only accepts finite numbers
+    pub fn new(min: T, max: T) -> Self {
+        NumberCtx { min, max, finite: true }
+    }
+}
+
+impl<T> NumberCtx<T>
+where
+    Self: Default,
+{
+    /// Creates a new `NumberCtx` that accepts numbers greater than or equal to
+    /// `min`. The largest accepted number is the maximum of the type.
+    ///
+    /// ```
+    /// # use parkour::prelude::*;
+    /// assert_eq!(NumberCtx::at_least(5u8), NumberCtx::new(5, 255));
+    /// ```
+    pub fn at_least(min: T) -> Self {
+        NumberCtx { min, ..Self::default() }
+    }
+
+    /// Creates a new `NumberCtx` that accepts numbers less than or equal to
+    /// `max`. The smallest accepted number is the minimum of the type.
+    pub fn at_most(max: T) -> Self {
+        NumberCtx { max, ..Self::default() }
+    }
+
+    /// Creates a new `NumberCtx` that accepts numbers between `min` and `max`
+    /// (inclusive). This is the same as [`NumberCtx::new`].
+    pub fn between(min: T, max: T) -> Self {
+        NumberCtx { min, max, ..Self::default() }
+    }
 }
 
 impl<T> NumberCtx<T>
 where
     T: Copy + PartialOrd + FromInputValue<'static, Context = Self> + std::fmt::Display,
 {
-    fn must_include(&self, n: T) -> Result<T, Error> {
+    pub(crate) fn must_include(&self, n: T) -> Result<T, Error> {
         if n >= self.min && n <= self.max {
             Ok(n)
         } else {
@@ -28,12 +72,35 @@ where
     }
 }
 
+/// Parses an integer. If it doesn't fit in the type, the error mentions the
+/// accepted range instead of just saying that the number is too large.
+fn parse_int<T>(value: &str, context: &NumberCtx<T>) -> Result<T, Error>
+where
+    T: FromStr<Err = ParseIntError> + std::fmt::Display,
+{
+    value.parse().map_err(|e: ParseIntError| match e.kind() {
+        IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => Error::unexpected_value(
+            format!("number {}", value),
+            Some(range("integer", &context.min, &context.max)),
+        ),
+        _ => e.into(),
+    })
+}
+
+fn range<T: std::fmt::Display>(kind: &str, min: T, max: T) -> PossibleValues {
+    PossibleValues::Range {
+        kind: kind.into(),
+        min: min.to_string(),
+        max: max.to_string(),
+    }
+}
+
 macro_rules! default_impl {
     ($( $t:ident ),*) => {
         $(
             impl Default for NumberCtx<$t> {
                 fn default() -> Self {
-                    NumberCtx { min: $t::MIN, max: $t::MAX }
+                    NumberCtx::new($t::MIN, $t::MAX)
                 }
             }
         )*
@@ -47,7 +114,7 @@ macro_rules! from_input_value {
                 type Context = NumberCtx<$t>;
 
                 fn from_input_value(value: &str, context: &Self::Context) -> Result<Self, Error> {
-                    context.must_include(value.parse()?)
+                    context.must_include(parse_int(value, context)?)
                 }
 
                 fn allow_leading_dashes(context: &Self::Context) -> bool {
@@ -55,14 +122,16 @@ macro_rules! from_input_value {
                 }
 
                 fn possible_values(context: &Self::Context) -> Option<PossibleValues> {
-                    Some(PossibleValues::Other(
-                        match (context.min, context.max) {
-                            ($t::MIN, $t::MAX) => "integer".into(),
-                            ($t::MIN, max) => format!("integer at most {}", max),
-                            (min, $t::MAX) => format!("integer at least {}", min),
-                            (min, max) => format!("integer between {} and {}", min, max),
+                    Some(match (context.min, context.max) {
+                        ($t::MIN, $t::MAX) => PossibleValues::Other("integer".into()),
+                        ($t::MIN, max) => {
+                            PossibleValues::Other(format!("integer at most {}", max))
+                        }
+                        (min, $t::MAX) => {
+                            PossibleValues::Other(format!("integer at least {}", min))
                         }
-                    ))
+                        (min, max) => range("integer", min, max),
+                    })
                 }
             }
         )*
@@ -73,7 +142,7 @@ macro_rules! from_input_value {
                 type Context = NumberCtx<$t>;
 
                 fn from_input_value(value: &str, context: &Self::Context) -> Result<Self, Error> {
-                    context.must_include(value.parse()?)
+                    context.must_include(parse_int(value, context)?)
                 }
 
                 fn allow_leading_dashes(context: &Self::Context) -> bool {
@@ -81,9 +150,7 @@ macro_rules! from_input_value {
                 }
 
                 fn possible_values(context: &Self::Context) -> Option<PossibleValues> {
-                    Some(PossibleValues::Other(
-                        format!("integer between {} and {}", context.min, context.max),
-                    ))
+                    Some(range("integer", context.min, context.max))
                 }
             }
         )*
@@ -94,15 +161,13 @@ macro_rules! from_input_value {
                 type Context = NumberCtx<$t>;
 
                 fn from_input_value(value: &str, context: &Self::Context) -> Result<Self, Error> {
-                    context.must_include(value.parse()?)
+                    context.must_include(parse_int(value, context)?)
                 }
 
                 fn allow_leading_dashes(_: &Self::Context) -> bool { false }
 
                 fn possible_values(context: &Self::Context) -> Option<PossibleValues> {
-                    Some(PossibleValues::Other(
-                        format!("integer between {} and {}", context.min, context.max),
-                    ))
+                    Some(range("integer", context.min, context.max))
                 }
             }
         )*
@@ -113,7 +178,14 @@ macro_rules! from_input_value {
                 type Context = NumberCtx<$t>;
 
                 fn from_input_value(value: &str, context: &Self::Context) -> Result<Self, Error> {
-                    context.must_include(value.parse()?)
+                    let n: $t = value.parse()?;
+                    if n.is_finite() {
+                        context.must_include(n)
+                    } else if context.finite {
+                        Err(Error::unexpected_value(value, Self::possible_values(context)))
+                    } else {
+                        Ok(n)
+                    }
                 }
 
                 fn allow_leading_dashes(context: &Self::Context) -> bool {
@@ -121,14 +193,18 @@ macro_rules! from_input_value {
                 }
 
                 fn possible_values(context: &Self::Context) -> Option<PossibleValues> {
-                    Some(PossibleValues::Other(
-                        match (context.min, context.max) {
-                            (min, max) if min == $t::MIN && max == $t::MAX => "number".into(),
-                            (min, max) if min == $t::MIN => format!("number at most {}", max),
-                            (min, max) if max == $t::MAX => format!("number at least {}", min),
-                            (min, max) => format!("number between {} and {}", min, max),
+                    Some(match (context.min, context.max) {
+                        (min, max) if min == $t::MIN && max == $t::MAX => {
+                            PossibleValues::Other("number".into())
+                        }
+                        (min, max) if min == $t::MIN => {
+                            PossibleValues::Other(format!("number at most {}", max))
                         }
-                    ))
+                        (min, max) if max == $t::MAX => {
+                            PossibleValues::Other(format!("number at least {}", min))
+                        }
+                        (min, max) => range("number", min, max),
+                    })
                 }
             }
         )*
@@ -136,6 +212,10 @@ macro_rules! from_input_value {
 }
 
 default_impl!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+default_impl! {
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+}
 
 from_input_value! { signed -> i8, i16, i32, i64, i128, isize }
 from_input_value! { signed_nonzero ->
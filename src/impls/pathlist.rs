@@ -0,0 +1,72 @@
+use std::path::PathBuf;
+
+use crate::help::PossibleValues;
+use crate::impls::StringCtx;
+use crate::{Error, ErrorInner, FromInputValue};
+
+/// A list of paths in a single argument, separated by the platform's path
+/// separator (`:` on Unix, `;` on Windows), like the `PATH` environment
+/// variable. For example, `a:b:c` is parsed as three paths on Unix.
+///
+/// ### Example
+///
+/// ```no_run
+/// # use parkour::prelude::*;
+/// use parkour::impls::{PathList, PathListCtx};
+///
+/// # let mut input: parkour::ArgsInput = todo!();
+/// let include: PathList = input.parse_value(&PathListCtx::default())?;
+/// # Ok::<(), parkour::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PathList(pub Vec<PathBuf>);
+
+impl PathList {
+    /// Returns the paths
+    pub fn into_paths(self) -> Vec<PathBuf> {
+        self.0
+    }
+}
+
+/// The parsing context for [`PathList`]
+pub struct PathListCtx {
+    /// The separator between the paths. The default is `;` on Windows and `:`
+    /// everywhere else.
+    pub separator: char,
+    /// The context for each path
+    pub inner: StringCtx,
+}
+
+impl Default for PathListCtx {
+    fn default() -> Self {
+        let separator = if cfg!(windows) { ';' } else { ':' };
+        PathListCtx { separator, inner: StringCtx::default() }
+    }
+}
+
+impl FromInputValue<'static> for PathList {
+    type Context = PathListCtx;
+
+    fn from_input_value(value: &str, context: &PathListCtx) -> Result<Self, Error> {
+        value
+            .split(context.separator)
+            .enumerate()
+            .map(|(i, s)| {
+                PathBuf::from_input_value(s, &context.inner)
+                    .map_err(|e| e.chain(ErrorInner::IncompleteValue(i)))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(PathList)
+    }
+
+    fn allow_leading_dashes(context: &PathListCtx) -> bool {
+        context.inner.allow_leading_dashes
+    }
+
+    fn possible_values(context: &PathListCtx) -> Option<PossibleValues> {
+        Some(PossibleValues::Other(format!(
+            "list of paths separated by `{}`",
+            context.separator
+        )))
+    }
+}
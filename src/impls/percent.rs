@@ -0,0 +1,58 @@
+use crate::help::PossibleValues;
+use crate::{Error, FromInputValue};
+
+/// A percentage between 0% and 100%, which is stored as a ratio between `0.0`
+/// and `1.0`, e.g. `50%` is parsed as `Percent(0.5)`.
+///
+/// ### Example
+///
+/// ```no_run
+/// # use parkour::prelude::*;
+/// use parkour::impls::{Percent, PercentCtx};
+///
+/// # let mut input: parkour::ArgsInput = todo!();
+/// let opacity: Percent = input.parse_value(&PercentCtx::default())?;
+/// # Ok::<(), parkour::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Percent(pub f64);
+
+impl Percent {
+    /// Returns the ratio, e.g. `0.5` for `50%`
+    pub fn ratio(self) -> f64 {
+        self.0
+    }
+}
+
+/// The parsing context for [`Percent`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PercentCtx {
+    /// Whether the value must end with a `%` sign. The default is `false`,
+    /// which means that `50` and `50%` are both accepted.
+    pub require_sign: bool,
+}
+
+impl FromInputValue<'static> for Percent {
+    type Context = PercentCtx;
+
+    fn from_input_value(value: &str, context: &PercentCtx) -> Result<Self, Error> {
+        let number = match value.strip_suffix('%') {
+            Some(number) => number,
+            None if context.require_sign => {
+                return Err(Error::unexpected_value(
+                    value,
+                    Self::possible_values(context),
+                ))
+            }
+            None => value,
+        };
+        match number.parse::<f64>() {
+            Ok(n) if (0.0..=100.0).contains(&n) => Ok(Percent(n / 100.0)),
+            _ => Err(Error::unexpected_value(value, Self::possible_values(context))),
+        }
+    }
+
+    fn possible_values(_: &PercentCtx) -> Option<PossibleValues> {
+        Some(PossibleValues::Other("percentage between 0% and 100%".into()))
+    }
+}
@@ -0,0 +1,141 @@
+use std::ops::{
+    Bound, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo,
+    RangeToInclusive,
+};
+
+use crate::help::PossibleValues;
+use crate::{Error, FromInputValue};
+
+/// A range with optional bounds, which is parsed from any of the range
+/// syntaxes: `1..5`, `1..=5`, `5..`, `..5`, `..=5` and `..`.
+///
+/// ### Example
+///
+/// ```no_run
+/// # use parkour::prelude::*;
+/// use std::ops::{Bound, RangeBounds};
+/// use parkour::impls::ParsedRange;
+///
+/// # let mut input: parkour::ArgsInput = todo!();
+/// let lines: ParsedRange<usize> = input.parse_value(&Default::default())?;
+/// if let Bound::Included(&max) = lines.end_bound() {
+///     println!("at most {} lines", max);
+/// }
+/// # Ok::<(), parkour::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedRange<T> {
+    /// The lower bound, which is either `Included` or `Unbounded`
+    pub start: Bound<T>,
+    /// The upper bound
+    pub end: Bound<T>,
+}
+
+impl<T> RangeBounds<T> for ParsedRange<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        as_ref(&self.start)
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        as_ref(&self.end)
+    }
+}
+
+fn as_ref<T>(bound: &Bound<T>) -> Bound<&T> {
+    match bound {
+        Bound::Included(t) => Bound::Included(t),
+        Bound::Excluded(t) => Bound::Excluded(t),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+/// Parses the bounds of a range, e.g. `1..=5`. Returns `None` if the value
+/// doesn't contain `..`.
+fn parse_bounds<'a, T: FromInputValue<'a>>(
+    value: &str,
+    context: &T::Context,
+) -> Result<Option<ParsedRange<T>>, Error> {
+    let (start, end) = match value.find("..") {
+        Some(i) => (&value[..i], &value[i + 2..]),
+        None => return Ok(None),
+    };
+
+    let start = match start {
+        "" => Bound::Unbounded,
+        s => Bound::Included(T::from_input_value(s, context)?),
+    };
+    let end = match end.strip_prefix('=') {
+        Some(e) => Bound::Included(T::from_input_value(e, context)?),
+        None if end.is_empty() => Bound::Unbounded,
+        None => Bound::Excluded(T::from_input_value(end, context)?),
+    };
+    Ok(Some(ParsedRange { start, end }))
+}
+
+impl<'a, T: FromInputValue<'a>> FromInputValue<'a> for ParsedRange<T> {
+    type Context = T::Context;
+
+    fn from_input_value(value: &str, context: &Self::Context) -> Result<Self, Error> {
+        match parse_bounds(value, context)? {
+            Some(range) => Ok(range),
+            None => Err(Error::unexpected_value(value, Self::possible_values(context))),
+        }
+    }
+
+    fn allow_leading_dashes(context: &Self::Context) -> bool {
+        T::allow_leading_dashes(context)
+    }
+
+    fn possible_values(_: &Self::Context) -> Option<PossibleValues> {
+        Some(PossibleValues::Other("range, e.g. 1..5, 1..=5, 5.. or ..5".into()))
+    }
+}
+
+macro_rules! impl_range {
+    ($( $ty:ident, $desc:literal, ($start:pat, $end:pat) => $range:expr; )*) => {
+        $(
+            impl<'a, T: FromInputValue<'a>> FromInputValue<'a> for $ty<T> {
+                type Context = T::Context;
+
+                fn from_input_value(value: &str, context: &Self::Context) -> Result<Self, Error> {
+                    match parse_bounds(value, context)? {
+                        Some(ParsedRange { start: $start, end: $end }) => Ok($range),
+                        _ => Err(Error::unexpected_value(value, Self::possible_values(context))),
+                    }
+                }
+
+                fn allow_leading_dashes(context: &Self::Context) -> bool {
+                    T::allow_leading_dashes(context)
+                }
+
+                fn possible_values(_: &Self::Context) -> Option<PossibleValues> {
+                    Some(PossibleValues::Other($desc.into()))
+                }
+            }
+        )*
+    };
+}
+
+impl_range! {
+    Range, "range, e.g. 1..5", (Bound::Included(a), Bound::Excluded(b)) => a..b;
+    RangeInclusive, "inclusive range, e.g. 1..=5", (Bound::Included(a), Bound::Included(b)) => a..=b;
+    RangeFrom, "range with a lower bound, e.g. 5..", (Bound::Included(a), Bound::Unbounded) => a..;
+    RangeTo, "range with an upper bound, e.g. ..5", (Bound::Unbounded, Bound::Excluded(b)) => ..b;
+    RangeToInclusive, "range with an inclusive upper bound, e.g. ..=5", (Bound::Unbounded, Bound::Included(b)) => ..=b;
+}
+
+impl FromInputValue<'static> for RangeFull {
+    type Context = ();
+
+    fn from_input_value(value: &str, context: &()) -> Result<Self, Error> {
+        if value == ".." {
+            Ok(..)
+        } else {
+            Err(Error::unexpected_value(value, Self::possible_values(context)))
+        }
+    }
+
+    fn possible_values(_: &()) -> Option<PossibleValues> {
+        Some(PossibleValues::Other("`..`".into()))
+    }
+}
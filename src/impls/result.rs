@@ -0,0 +1,23 @@
+use palex::ArgsInput;
+
+use crate::{Error, FromInput};
+
+/// Captures the error instead of propagating it. This is useful for tolerant
+/// parsing, e.g. when a malformed subcommand shouldn't prevent other arguments
+/// from being parsed.
+///
+/// [`Error::no_value`], [`Error::early_exit`], [`Error::version`] and
+/// [`Error::help_topic`] errors are still propagated (see
+/// [`Error::is_control_flow`]). Note that when an error is captured, the input
+/// is left at the position where the error occurred.
+impl<'a, T: FromInput<'a>> FromInput<'a> for Result<T, Error> {
+    type Context = T::Context;
+
+    fn from_input(input: &mut ArgsInput, context: &Self::Context) -> Result<Self, Error> {
+        match T::from_input(input, context) {
+            Ok(value) => Ok(Ok(value)),
+            Err(e) if e.is_control_flow() => Err(e),
+            Err(e) => Ok(Err(e)),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use ::semver::{Version, VersionReq};
+
+use crate::help::PossibleValues;
+use crate::{Error, FromInputValue};
+
+impl FromInputValue<'static> for Version {
+    type Context = ();
+
+    fn from_input_value(value: &str, context: &()) -> Result<Self, Error> {
+        Version::parse(value).map_err(|e| {
+            Error::unexpected_value(value, Self::possible_values(context)).with_source(e)
+        })
+    }
+
+    fn possible_values(_: &()) -> Option<PossibleValues> {
+        Some(PossibleValues::Other("semantic version".into()))
+    }
+}
+
+impl FromInputValue<'static> for VersionReq {
+    type Context = ();
+
+    fn from_input_value(value: &str, context: &()) -> Result<Self, Error> {
+        VersionReq::parse(value).map_err(|e| {
+            Error::unexpected_value(value, Self::possible_values(context)).with_source(e)
+        })
+    }
+
+    fn possible_values(_: &()) -> Option<PossibleValues> {
+        Some(PossibleValues::Other("version requirement".into()))
+    }
+}
+
+#[test]
+fn test_semver() {
+    let version = Version::from_input_value("1.2.3", &()).unwrap();
+    assert_eq!(version, Version::new(1, 2, 3));
+    let req = VersionReq::from_input_value(">=1.0, <2", &()).unwrap();
+    assert!(req.matches(&version));
+
+    let e = Version::from_input_value("1.2", &()).unwrap_err();
+    assert_eq!(e.to_string(), "unexpected value `1.2`, expected semantic version");
+}
@@ -2,8 +2,10 @@ use std::borrow::Cow;
 use std::ffi::OsString;
 use std::path::PathBuf;
 
+use palex::ArgsInput;
+
 use crate::help::PossibleValues;
-use crate::{Error, FromInputValue};
+use crate::{Error, FromInputValue, Parse};
 
 /// The parsing context for strings
 pub struct StringCtx {
@@ -64,6 +66,8 @@ impl FromInputValue<'static> for String {
     }
 }
 
+/// A value after a flag that isn't valid Unicode is preserved if the input was
+/// created with [`ArgsInput::from_args_os`], see [`ArgsInput::eat_value_os`].
 impl FromInputValue<'static> for OsString {
     type Context = StringCtx;
 
@@ -82,6 +86,18 @@ impl FromInputValue<'static> for OsString {
         context.allow_leading_dashes
     }
 
+    fn from_input_after_flag(
+        input: &mut ArgsInput,
+        context: &StringCtx,
+    ) -> Result<Self, Error> {
+        match input.eat_value_os() {
+            Some(value) => {
+                check_os_length(value, context, Self::possible_values(context))
+            }
+            None => input.parse_value(context),
+        }
+    }
+
     fn possible_values(context: &Self::Context) -> Option<PossibleValues> {
         Some(PossibleValues::Other(match (context.min_length, context.max_length) {
             (0, usize::MAX) => "string".into(),
@@ -93,6 +109,8 @@ impl FromInputValue<'static> for OsString {
     }
 }
 
+/// A value after a flag that isn't valid Unicode is preserved if the input was
+/// created with [`ArgsInput::from_args_os`], see [`ArgsInput::eat_value_os`].
 impl FromInputValue<'static> for PathBuf {
     type Context = StringCtx;
 
@@ -111,6 +129,19 @@ impl FromInputValue<'static> for PathBuf {
         context.allow_leading_dashes
     }
 
+    fn from_input_after_flag(
+        input: &mut ArgsInput,
+        context: &StringCtx,
+    ) -> Result<Self, Error> {
+        match input.eat_value_os() {
+            Some(value) => {
+                check_os_length(value, context, Self::possible_values(context))
+                    .map(Into::into)
+            }
+            None => input.parse_value(context),
+        }
+    }
+
     fn possible_values(context: &Self::Context) -> Option<PossibleValues> {
         Some(PossibleValues::Other(match (context.min_length, context.max_length) {
             (0, usize::MAX) => "path".into(),
@@ -150,3 +181,18 @@ impl FromInputValue<'static> for Cow<'static, str> {
         }))
     }
 }
+
+fn check_os_length(
+    value: OsString,
+    context: &StringCtx,
+    expected: Option<PossibleValues>,
+) -> Result<OsString, Error> {
+    if value.len() < context.min_length || value.len() > context.max_length {
+        Err(Error::unexpected_value(
+            format!("string with length {}", value.len()),
+            expected,
+        ))
+    } else {
+        Ok(value)
+    }
+}
@@ -0,0 +1,39 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::help::PossibleValues;
+use crate::{Error, FromInputValue};
+
+/// The parsing context for [`SystemTime`], which is parsed from a unix
+/// timestamp, e.g. `1700000000`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimestampCtx {
+    /// The timestamp is in seconds since the unix epoch. This is the default.
+    #[default]
+    Seconds,
+    /// The timestamp is in milliseconds since the unix epoch
+    Millis,
+}
+
+impl FromInputValue<'static> for SystemTime {
+    type Context = TimestampCtx;
+
+    fn from_input_value(value: &str, context: &TimestampCtx) -> Result<Self, Error> {
+        let n: u64 = value.parse().map_err(|_| {
+            Error::unexpected_value(value, Self::possible_values(context))
+        })?;
+        let duration = match context {
+            TimestampCtx::Seconds => Duration::from_secs(n),
+            TimestampCtx::Millis => Duration::from_millis(n),
+        };
+        UNIX_EPOCH
+            .checked_add(duration)
+            .ok_or_else(|| Error::unexpected_value(value, Self::possible_values(context)))
+    }
+
+    fn possible_values(context: &TimestampCtx) -> Option<PossibleValues> {
+        Some(PossibleValues::Other(match context {
+            TimestampCtx::Seconds => "unix timestamp in seconds".into(),
+            TimestampCtx::Millis => "unix timestamp in milliseconds".into(),
+        }))
+    }
+}
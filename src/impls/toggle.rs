@@ -0,0 +1,67 @@
+use crate::help::PossibleValues;
+use crate::{Error, FromInputValue};
+
+/// A value that can be enabled or disabled, e.g. with `on` and `off`. When it
+/// is enabled, it contains the value configured in the [`EnableDisable`]
+/// context; when it is disabled, it contains `None`.
+///
+/// ### Example
+///
+/// ```no_run
+/// # use parkour::prelude::*;
+/// use parkour::impls::{EnableDisable, Toggle};
+///
+/// # let mut input: parkour::ArgsInput = todo!();
+/// let ctx = EnableDisable::new(8080u16);
+/// let Toggle(port) = input.parse_value(&ctx)?;
+/// # Ok::<(), parkour::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Toggle<T>(pub Option<T>);
+
+impl<T> Toggle<T> {
+    /// Returns the value if it is enabled
+    pub fn into_inner(self) -> Option<T> {
+        self.0
+    }
+}
+
+/// The parsing context for [`Toggle`]. The strings are compared
+/// case-insensitively.
+#[derive(Debug, Clone)]
+pub struct EnableDisable<'a, T> {
+    /// The string that enables the value. The default is `on`.
+    pub enable: &'a str,
+    /// The string that disables the value. The default is `off`.
+    pub disable: &'a str,
+    /// The value that is returned when it is enabled
+    pub value: T,
+}
+
+impl<'a, T> EnableDisable<'a, T> {
+    /// Creates a new `EnableDisable` instance that accepts `on` and `off`
+    pub fn new(value: T) -> Self {
+        EnableDisable { enable: "on", disable: "off", value }
+    }
+}
+
+impl<'a, T: Clone + 'a> FromInputValue<'a> for Toggle<T> {
+    type Context = EnableDisable<'a, T>;
+
+    fn from_input_value(value: &str, context: &Self::Context) -> Result<Self, Error> {
+        if value.eq_ignore_ascii_case(context.enable) {
+            Ok(Toggle(Some(context.value.clone())))
+        } else if value.eq_ignore_ascii_case(context.disable) {
+            Ok(Toggle(None))
+        } else {
+            Err(Error::unexpected_value(value, Self::possible_values(context)))
+        }
+    }
+
+    fn possible_values(context: &Self::Context) -> Option<PossibleValues> {
+        Some(PossibleValues::OneOf(vec![
+            PossibleValues::String(context.enable.into()),
+            PossibleValues::String(context.disable.into()),
+        ]))
+    }
+}
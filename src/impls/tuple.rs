@@ -1,21 +1,51 @@
 use crate::help::PossibleValues;
 use crate::{Error, ErrorInner, FromInputValue};
 
+/// The parsing context for tuples. The elements of a tuple are separated by
+/// a delimiter within the same argument, e.g. `--size 800,600`.
 #[derive(Debug)]
 pub struct TupleCtx<C> {
+    /// The delimiter that separates the elements. The default is a comma.
     pub delimiter: char,
+    /// Per-position delimiters: `delimiters[i]` separates element `i` and
+    /// `i + 1`. Positions without an entry use `delimiter`.
+    pub delimiters: Vec<char>,
+    /// The contexts of the elements, as a tuple
     pub inner: C,
 }
 
 impl<C> TupleCtx<C> {
+    /// Creates a new `TupleCtx` instance that uses the same delimiter for all
+    /// elements
     pub fn new(delimiter: char, inner: C) -> Self {
-        Self { delimiter, inner }
+        Self { delimiter, delimiters: Vec::new(), inner }
+    }
+
+    /// Creates a new `TupleCtx` instance with per-position delimiters, e.g.
+    /// `[':', ':']` for `12:30:00`
+    pub fn with_delimiters(delimiters: Vec<char>, inner: C) -> Self {
+        Self { delimiter: ',', delimiters, inner }
+    }
+
+    fn delimiter_at(&self, i: usize) -> char {
+        self.delimiters.get(i).copied().unwrap_or(self.delimiter)
     }
 }
 
 impl<C: Default> Default for TupleCtx<C> {
     fn default() -> Self {
-        TupleCtx { delimiter: ',', inner: C::default() }
+        TupleCtx { delimiter: ',', delimiters: Vec::new(), inner: C::default() }
+    }
+}
+
+fn next_part<'v>(rest: &mut Option<&'v str>, delimiter: char) -> Option<&'v str> {
+    let s = rest.take()?;
+    match s.split_once(delimiter) {
+        Some((part, tail)) => {
+            *rest = Some(tail);
+            Some(part)
+        }
+        None => Some(s),
     }
 }
 
@@ -25,11 +55,12 @@ macro_rules! impl_tuple {
             type Context = TupleCtx<($( $t::Context ),* ,)>;
 
             fn from_input_value(value: &str, context: &Self::Context) -> Result<Self, Error> {
-                let mut iter = value.split(context.delimiter);
+                let mut rest = Some(value);
 
                 $(
                     let $v = $t::from_input_value(
-                        iter.next().ok_or_else(|| ErrorInner::IncompleteValue($i + 1))?,
+                        next_part(&mut rest, context.delimiter_at($i))
+                            .ok_or_else(|| ErrorInner::IncompleteValue($i + 1))?,
                         &context.inner.$i,
                     )?;
                 )*
@@ -150,3 +181,16 @@ impl_tuple!(
     T11 v11 10,
     T12 v12 11,
 );
+
+#[test]
+fn test_delimiters() {
+    let ctx = TupleCtx::with_delimiters(vec![':', ':'], Default::default());
+    let time = <(u8, u8, u8)>::from_input_value("12:30:00", &ctx).unwrap();
+    assert_eq!(time, (12, 30, 0));
+
+    let ctx = TupleCtx::with_delimiters(vec!['x'], Default::default());
+    let size = <(u32, u32, String)>::from_input_value("800x600,px", &ctx).unwrap();
+    assert_eq!(size, (800, 600, "px".to_string()));
+
+    assert!(<(u8, u8, u8)>::from_input_value("12,30", &TupleCtx::default()).is_err());
+}
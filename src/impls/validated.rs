@@ -0,0 +1,121 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use crate::help::PossibleValues;
+use crate::{Error, FromInputValue};
+
+/// A value that was checked by a validation function after parsing. The
+/// validation function is provided by the [`ValidatedCtx`]; its type `F` is
+/// usually inferred from the context.
+///
+/// ### Example
+///
+/// ```no_run
+/// # use parkour::prelude::*;
+/// use parkour::impls::{Validated, ValidatedCtx};
+///
+/// # let mut input: parkour::ArgsInput = todo!();
+/// let ctx = ValidatedCtx::new(NumberCtx::default(), |n: &u32| n % 2 == 0);
+/// let even: Validated<u32, _> = input.parse_value(&ctx)?;
+/// # Ok::<(), parkour::Error>(())
+/// ```
+pub struct Validated<T, F = fn(&T) -> bool>(pub T, PhantomData<fn() -> F>);
+
+impl<T, F> Validated<T, F> {
+    /// Creates a new `Validated` instance
+    pub fn new(value: T) -> Self {
+        Validated(value, PhantomData)
+    }
+
+    /// Returns the inner value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T, F> Deref for Validated<T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: fmt::Debug, F> fmt::Debug for Validated<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Validated").field(&self.0).finish()
+    }
+}
+
+impl<T: Clone, F> Clone for Validated<T, F> {
+    fn clone(&self) -> Self {
+        Validated::new(self.0.clone())
+    }
+}
+
+impl<T: Copy, F> Copy for Validated<T, F> {}
+
+impl<T: PartialEq, F> PartialEq for Validated<T, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Eq, F> Eq for Validated<T, F> {}
+
+impl<T: Hash, F> Hash for Validated<T, F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+/// The parsing context for [`Validated`]. It contains the context of the inner
+/// value and a function that must return `true` for the parsed value to be
+/// accepted.
+#[derive(Clone, Copy)]
+pub struct ValidatedCtx<C, F> {
+    /// The context of the inner value
+    pub inner: C,
+    /// The validation function
+    pub validate: F,
+}
+
+impl<C, F> ValidatedCtx<C, F> {
+    /// Creates a new `ValidatedCtx` instance
+    pub fn new(inner: C, validate: F) -> Self {
+        ValidatedCtx { inner, validate }
+    }
+}
+
+impl<C: fmt::Debug, F> fmt::Debug for ValidatedCtx<C, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ValidatedCtx").field("inner", &self.inner).finish_non_exhaustive()
+    }
+}
+
+impl<'a, T, F> FromInputValue<'a> for Validated<T, F>
+where
+    T: FromInputValue<'a>,
+    F: Fn(&T) -> bool + 'a,
+{
+    type Context = ValidatedCtx<T::Context, F>;
+
+    fn from_input_value(value: &str, context: &Self::Context) -> Result<Self, Error> {
+        let parsed = T::from_input_value(value, &context.inner)?;
+        if (context.validate)(&parsed) {
+            Ok(Validated::new(parsed))
+        } else {
+            Err(Error::unexpected_value(value, None))
+        }
+    }
+
+    fn allow_leading_dashes(context: &Self::Context) -> bool {
+        T::allow_leading_dashes(&context.inner)
+    }
+
+    fn possible_values(context: &Self::Context) -> Option<PossibleValues> {
+        T::possible_values(&context.inner)
+    }
+}
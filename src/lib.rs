@@ -209,32 +209,22 @@
 //! #     }
 //! # }
 //! #
-//! use std::error::Error;
-//!
 //! fn main() {
 //!     match Command::from_input(&mut parkour::parser(), &()) {
 //!         Ok(command) => {
 //!             println!("parsed successfully");
 //!         }
 //!         Err(e) if e.is_early_exit() => {}
-//!         Err(e) => {
-//!             eprint!("{}", e);
-//!             let mut source = e.source();
-//!             while let Some(s) = source {
-//!                 eprint!(": {}", s);
-//!                 source = s.source();
-//!             }
-//!             eprintln!();
-//!         }
+//!         Err(e) if e.is_version() => println!("{}", e),
+//!         Err(e) => parkour::print_error(&e),
 //!     }
 //! }
 //! ```
 //!
 //! The [`parser`] function creates a new parser instance, which
 //! implements [`Parse`]. This is used to parse the `Command`. If it fails, we
-//! print the error with its sources. I will implement a more convenient method
-//! for this, I just haven't gotten around to it yet. I also plan to implement
-//! ANSI color support.
+//! print the error with its sources using [`print_error`]. I also plan to
+//! implement ANSI color support.
 //!
 //! What's with the `e.is_early_exit()`, you might wonder? This error is
 //! returned when parsing was aborted and can be ignored. This error can be used
@@ -269,6 +259,21 @@
 //! }
 //! ```
 //!
+//! The `--version` and `-V` flags can be handled with
+//! [`Parse::parse_version_flag`]. It returns an error containing the version,
+//! which is printed by the `e.is_version()` branch in the main function above:
+//!
+//! ```no_run
+//! # use parkour::prelude::*;
+//! # let input: &mut ArgsInput = todo!();
+//! while !input.is_empty() {
+//!     input.parse_version_flag(env!("CARGO_PKG_VERSION"))?;
+//!
+//!     // <snip>
+//! }
+//! # Ok::<(), parkour::Error>(())
+//! ```
+//!
 //! There is one special case that isn't handled yet: The argument `--` usually
 //! causes the remaining tokens to be treated as positional arguments, even if
 //! they start with a dash. This is easily implemented:
@@ -299,13 +304,18 @@
 //! }
 //! ```
 //!
-//! Unfortunately, this must be repeated in every subcommand.
+//! The setting is stored in the [`ArgsInput`], so it is inherited by
+//! subcommands: once `--` was seen, nested `from_input` calls treat all
+//! remaining arguments as positional, without having to detect `--` again.
+//! However, a subcommand still has to handle `--` itself if it appears after
+//! the subcommand's name, e.g. `show -- -x`. The `FromInput` derive macro does
+//! this automatically.
 
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
 
 pub use error::{Error, ErrorInner};
-pub use from_input::{FromInput, FromInputValue};
+pub use from_input::{ArgGroup, FromInput, FromInputValue};
 pub use parse::Parse;
 
 pub use palex::ArgsInput;
@@ -325,11 +335,26 @@ pub mod util;
 pub type Result<T> = std::result::Result<T, Error>;
 
 /// Create a new parser, which can be used to parse the
-/// command-line arguments of the program.
+/// command-line arguments of the program. This panics if an argument isn't
+/// valid Unicode; use [`ArgsInput::from_args_os`] to accept such arguments.
 pub fn parser() -> ArgsInput {
     ArgsInput::from_args()
 }
 
+/// Prints the error and all its sources to stderr, separated by colons, e.g.
+///
+/// ```text
+/// unexpected value `x`: in `--num`
+/// ```
+pub fn print_error(e: &Error) {
+    let mut message = e.to_string();
+    for s in e.sources() {
+        message.push_str(": ");
+        message.push_str(&s.to_string());
+    }
+    eprintln!("{}", message);
+}
+
 /// A prelude to make it easier to import all the needed types and traits. Use
 /// it like this:
 ///
@@ -341,7 +366,7 @@ pub mod prelude {
         Action, Append, Dec, Inc, Reset, Set, SetOnce, SetPositional, SetSubcommand,
         Unset,
     };
-    pub use crate::impls::{ListCtx, NumberCtx, StringCtx};
+    pub use crate::impls::{ArrayCtx, ListCtx, NumberCtx, StringCtx, TupleCtx};
     pub use crate::util::{ArgCtx, Flag, PosCtx};
     pub use crate::{ArgsInput, FromInput, FromInputValue, Parse};
 }
@@ -1,5 +1,8 @@
 use palex::ArgsInput;
 
+use crate::help::PossibleValues;
+use crate::impls::NumberCtx;
+use crate::util::Flag;
 use crate::{Error, ErrorInner, FromInput, FromInputValue};
 
 /// An extension trait of [`palex::ArgsInput`], the trait for types that can
@@ -11,6 +14,38 @@ pub trait Parse: Sized {
     /// Parse something using the [`FromInput`] trait
     fn parse<'a, F: FromInput<'a>>(&mut self, context: &F::Context) -> Result<F, Error>;
 
+    /// Parse something using the [`FromInput`] trait. If parsing fails, the
+    /// `help` callback is invoked (e.g. to print a help message), and the
+    /// error is converted to an [`Error::early_exit`] error, so the program
+    /// can exit cleanly. The original error is attached as its source.
+    ///
+    /// [`Error::no_value`], [`Error::early_exit`], [`Error::version`] and
+    /// [`Error::help_topic`] errors are returned unchanged without invoking
+    /// `help`, so an absent argument can still be handled by the caller.
+    ///
+    /// ```no_run
+    /// # use parkour::prelude::*;
+    /// # let mut input: parkour::ArgsInput = todo!();
+    /// let n: usize = input.parse_or_help(&Flag::Short("n").into(), || {
+    ///     println!("Usage: count -n <N>");
+    /// })?;
+    /// # Ok::<(), parkour::Error>(())
+    /// ```
+    fn parse_or_help<'a, F: FromInput<'a>>(
+        &mut self,
+        context: &F::Context,
+        help: impl Fn(),
+    ) -> Result<F, Error> {
+        self.parse(context).map_err(|e| {
+            if e.is_control_flow() {
+                e
+            } else {
+                help();
+                Error::early_exit().with_source(e)
+            }
+        })
+    }
+
     /// Parse something using the [`FromInput`] trait, but convert
     /// [`Error::no_value`] to [`Option::None`]. This is useful when you want to
     /// bubble up all errors except for [`Error::no_value`]:
@@ -29,12 +64,81 @@ pub trait Parse: Sized {
         context: &F::Context,
     ) -> Result<Option<F>, Error>;
 
+    /// Parse a subcommand using the [`FromInput`] trait. This works like
+    /// [`Parse::try_parse`], but errors are annotated with the name of the
+    /// subcommand:
+    ///
+    /// ```no_run
+    /// # use parkour::prelude::*;
+    /// # let input: parkour::ArgsInput = todo!();
+    /// # struct Show;
+    /// # impl FromInput<'static> for Show {
+    /// #     type Context = ();
+    /// #     fn from_input(_: &mut ArgsInput, _: &()) -> parkour::Result<Self> {
+    /// #         todo!()
+    /// #     }
+    /// # }
+    /// if let Some(show) = input.parse_subcommand::<Show>("show", &())? {
+    ///     // do something with show
+    /// }
+    /// # Ok::<(), parkour::Error>(())
+    /// ```
+    ///
+    /// When parsing the subcommand fails, the error could look like this:
+    ///
+    /// ```text
+    /// missing value: in subcommand show
+    /// ```
+    #[inline]
+    fn parse_subcommand<'a, F: FromInput<'a>>(
+        &mut self,
+        name: &str,
+        context: &F::Context,
+    ) -> Result<Option<F>, Error> {
+        self.try_parse(context)
+            .map_err(|e| e.chain(ErrorInner::InSubcommand(name.into())))
+    }
+
     /// Parse a _value_ using the [`FromInputValue`] trait.
+    ///
+    /// The value may only start with a dash if
+    /// [`FromInputValue::allow_leading_dashes`] returns `true`, or if
+    /// dashes are ignored (e.g. after `--`, see
+    /// [`ArgsInput::set_ignore_dashes`]).
     fn parse_value<'a, V: FromInputValue<'a>>(
         &mut self,
         context: &V::Context,
     ) -> Result<V, Error>;
 
+    /// Parse a _value_ like [`Parse::parse_value`], but if the value begins and
+    /// ends with the same quote character (`"` or `'`), the quotes are
+    /// stripped before the value is passed to [`FromInputValue`]. For
+    /// example, `'foo'` is parsed as `foo`.
+    fn parse_value_unquoted<'a, V: FromInputValue<'a>>(
+        &mut self,
+        context: &V::Context,
+    ) -> Result<V, Error> {
+        self.parse_value::<Unquoted<V>>(context).map(|Unquoted(value)| value)
+    }
+
+    /// Parse a _value_ using the [`FromInputValue`] trait, and transform it
+    /// with the function `f`:
+    ///
+    /// ```no_run
+    /// # use parkour::prelude::*;
+    /// # let mut input: parkour::ArgsInput = todo!();
+    /// let name = input.parse_value_map(&StringCtx::default(), |s: String| s.to_uppercase())?;
+    /// # Ok::<(), parkour::Error>(())
+    /// ```
+    #[inline]
+    fn parse_value_map<'a, V: FromInputValue<'a>, U>(
+        &mut self,
+        context: &V::Context,
+        f: impl FnOnce(V) -> U,
+    ) -> Result<U, Error> {
+        self.parse_value(context).map(f)
+    }
+
     /// Parse a _value_ using the [`FromInputValue`] trait, but convert
     /// [`Error::no_value`] to [`Option::None`]. This is useful when you want to
     /// bubble up all errors except for [`Error::no_value`]:
@@ -60,6 +164,122 @@ pub trait Parse: Sized {
         }
     }
 
+    /// Parse a positional argument with the given name. Returns `None` if
+    /// there is no value. If the value is invalid, the name is added to the
+    /// error, e.g. ``in `<file>` ``.
+    ///
+    /// ```no_run
+    /// # use parkour::prelude::*;
+    /// # let mut input: parkour::ArgsInput = todo!();
+    /// if let Some(file) = input.parse_positional::<String>("file", Default::default())? {
+    ///     // do something with file
+    /// }
+    /// # Ok::<(), parkour::Error>(())
+    /// ```
+    #[inline]
+    fn parse_positional<'a, V: FromInputValue<'a>>(
+        &mut self,
+        name: &str,
+        context: V::Context,
+    ) -> Result<Option<V>, Error> {
+        self.try_parse_value(&context)
+            .map_err(|e| e.chain(ErrorInner::InArgument(format!("<{}>", name))))
+    }
+
+    /// Parse a number that must be between `min` and `max` (inclusive). This
+    /// is a shorthand for [`Parse::parse_value`] with a [`NumberCtx`]:
+    ///
+    /// ```no_run
+    /// # use parkour::prelude::*;
+    /// # let mut input: parkour::ArgsInput = todo!();
+    /// let level = input.parse_int_in_range(1u8, 10)?;
+    /// # Ok::<(), parkour::Error>(())
+    /// ```
+    #[inline]
+    fn parse_int_in_range<T>(&mut self, min: T, max: T) -> Result<T, Error>
+    where
+        T: FromInputValue<'static, Context = NumberCtx<T>>,
+    {
+        self.parse_value(&NumberCtx::new(min, max))
+    }
+
+    /// Parse a flag followed by a _value_, e.g. `-o foo`, `--out=foo` or
+    /// `-ofoo`. Returns `None` if the flag isn't present. If the flag is
+    /// present but no value follows, a [`Error::missing_value`] error is
+    /// returned.
+    ///
+    /// ```no_run
+    /// # use parkour::prelude::*;
+    /// # let mut input: parkour::ArgsInput = todo!();
+    /// if let Some(out) = input.parse_named::<String>(&Flag::LongShort("out", "o"), &Default::default())? {
+    ///     // do something with out
+    /// }
+    /// # Ok::<(), parkour::Error>(())
+    /// ```
+    fn parse_named<'a, V: FromInputValue<'a>>(
+        &mut self,
+        flag: &Flag<'_>,
+        context: &V::Context,
+    ) -> Result<Option<V>, Error>;
+
+    /// Parse a flag followed by a list of key-value pairs, e.g. `-D a=1,b=2`.
+    /// The pairs are separated by `item_sep`, keys and values are separated by
+    /// `pair_sep`. Keys and values are parsed with their default context.
+    ///
+    /// If the flag isn't present, a [`Error::no_value`] error is returned.
+    ///
+    /// ```no_run
+    /// # use parkour::prelude::*;
+    /// # let mut input: parkour::ArgsInput = todo!();
+    /// let defines: Vec<(String, u32)> =
+    ///     input.parse_kv_list(&Flag::Short("D"), '=', ',')?;
+    /// # Ok::<(), parkour::Error>(())
+    /// ```
+    fn parse_kv_list<'a, K, V>(
+        &mut self,
+        flag: &Flag<'_>,
+        pair_sep: char,
+        item_sep: char,
+    ) -> Result<Vec<(K, V)>, Error>
+    where
+        K: FromInputValue<'a>,
+        K::Context: Default,
+        V: FromInputValue<'a>,
+        V::Context: Default;
+
+    /// Returns the next _value_ as a string slice without allocating, and
+    /// consumes it. Returns `None` if there is no value.
+    ///
+    /// The returned slice borrows the input, so it must be dropped (or
+    /// converted to an owned value) before the input can be advanced:
+    ///
+    /// ```no_run
+    /// # use parkour::prelude::*;
+    /// # let mut input: parkour::ArgsInput = todo!();
+    /// if input.parse_long_flag("name") {
+    ///     let name: &str = input.parse_value_ref().ok_or_else(parkour::Error::missing_value)?;
+    ///     println!("Hello, {}!", name);
+    /// }
+    /// # Ok::<(), parkour::Error>(())
+    /// ```
+    fn parse_value_ref(&mut self) -> Option<&str>;
+
+    /// Like [`Parse::parse_value_ref`], but the value may start with a dash.
+    /// This is useful for values such as `-`, which often means stdin:
+    ///
+    /// ```no_run
+    /// # use parkour::prelude::*;
+    /// # let mut input: parkour::ArgsInput = todo!();
+    /// if input.parse_short_flag("o") {
+    ///     let out = input.parse_value_allow_dashes().ok_or_else(parkour::Error::missing_value)?;
+    ///     if out == "-" {
+    ///         // write to stdout
+    ///     }
+    /// }
+    /// # Ok::<(), parkour::Error>(())
+    /// ```
+    fn parse_value_allow_dashes(&mut self) -> Option<&str>;
+
     /// Convenience function for parsing a flag with a single dash, like `-h` or
     /// `-foo`. Returns `true` if it succeeded.
     fn parse_short_flag(&mut self, flag: &str) -> bool;
@@ -68,15 +288,259 @@ pub trait Parse: Sized {
     /// `--foo`. Returns `true` if it succeeded.
     fn parse_long_flag(&mut self, flag: &str) -> bool;
 
+    /// Convenience function for parsing a flag with all its aliases, e.g.
+    /// `--verbose` or `-v` for `Flag::LongShort("verbose", "v")`. Returns
+    /// `true` if one of them matched.
+    ///
+    /// ```no_run
+    /// # use parkour::prelude::*;
+    /// # let mut input: parkour::ArgsInput = todo!();
+    /// let verbose = input.parse_bool_flag(&Flag::LongShort("verbose", "v"));
+    /// ```
+    fn parse_bool_flag(&mut self, flag: &Flag<'_>) -> bool {
+        flag.long_aliases().iter().any(|long| self.parse_long_flag(long))
+            || flag.short_aliases().iter().any(|short| self.parse_short_flag(short))
+    }
+
+    /// Parses a flag as often as it occurs consecutively, and returns how
+    /// often it matched, e.g. 3 for `-v -v -v` or `-vvv`. Note that this
+    /// stops at the first argument that doesn't match, so it should be called
+    /// in the main parsing loop if other arguments may appear in between.
+    ///
+    /// ```no_run
+    /// # use parkour::prelude::*;
+    /// # let mut input: parkour::ArgsInput = todo!();
+    /// let verbosity = input.count_flag(&Flag::LongShort("verbose", "v"));
+    /// ```
+    #[inline]
+    fn count_flag(&mut self, flag: &Flag<'_>) -> usize {
+        let mut count = 0;
+        while self.parse_bool_flag(flag) {
+            count += 1;
+        }
+        count
+    }
+
+    /// Tries each of the given flags, and sets the `bool` of the first one that
+    /// matches to `true`. Returns whether a flag matched. This is useful for a
+    /// block of boolean flags that can appear in any order:
+    ///
+    /// ```no_run
+    /// # use parkour::prelude::*;
+    /// # let mut input: parkour::ArgsInput = todo!();
+    /// let (mut verbose, mut quiet) = (false, false);
+    /// while input.parse_flags(&mut [
+    ///     (&Flag::LongShort("verbose", "v"), &mut verbose),
+    ///     (&Flag::LongShort("quiet", "q"), &mut quiet),
+    /// ])? {}
+    /// # Ok::<(), parkour::Error>(())
+    /// ```
+    fn parse_flags(
+        &mut self,
+        flags: &mut [(&Flag<'_>, &mut bool)],
+    ) -> Result<bool, Error> {
+        for (flag, value) in flags {
+            if self.parse_bool_flag(flag) {
+                **value = true;
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Returns `true` if the next token is a flag or named argument (e.g.
+    /// `-h`, `--help=config`), so it shouldn't be consumed as a value. This
+    /// doesn't consume any input.
+    ///
+    /// ```no_run
+    /// # use parkour::prelude::*;
+    /// # let mut input: parkour::ArgsInput = todo!();
+    /// let mut values = Vec::new();
+    /// while !input.next_is_flag() {
+    ///     match input.parse_value_ref() {
+    ///         Some(v) => values.push(v.to_string()),
+    ///         None => break,
+    ///     }
+    /// }
+    /// ```
+    fn next_is_flag(&self) -> bool;
+
+    /// Convenience function for parsing a flag that can be negated, e.g.
+    /// `--color`, `-c` and `--no-color`. Returns `Some(true)` if the flag is
+    /// present, `Some(false)` if the negated flag is present, and `None`
+    /// otherwise.
+    ///
+    /// ```no_run
+    /// # use parkour::prelude::*;
+    /// # let mut input: parkour::ArgsInput = todo!();
+    /// let mut color = None;
+    /// if let Some(c) = input.parse_toggle("color", Some("c")) {
+    ///     color = Some(c);
+    /// }
+    /// ```
+    fn parse_toggle(&mut self, name: &str, short: Option<&str>) -> Option<bool> {
+        if self.parse_long_flag(name)
+            || short.is_some_and(|short| self.parse_short_flag(short))
+        {
+            Some(true)
+        } else if self.parse_long_flag(&format!("no-{}", name)) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Convenience function for parsing the `--version` or `-V` flag. If it is
+    /// present, a [`Error::version`] error containing the version is returned,
+    /// which can be printed:
+    ///
+    /// ```no_run
+    /// # use parkour::prelude::*;
+    /// # let mut input: parkour::ArgsInput = todo!();
+    /// match input.parse_version_flag(env!("CARGO_PKG_VERSION")) {
+    ///     Err(e) if e.is_version() => println!("{}", e),
+    ///     _ => {}
+    /// }
+    /// ```
+    fn parse_version_flag(&mut self, version: &str) -> Result<(), Error>;
+
+    /// Convenience function for parsing the `--help` or `-h` flag. Returns
+    /// `true` if it is present. The long flag can have a topic, e.g.
+    /// `--help=net`, in which case a [`Error::help_topic`] error containing
+    /// the topic is returned. An empty topic (`--help=`) is treated like
+    /// `--help`:
+    ///
+    /// ```no_run
+    /// # use parkour::prelude::*;
+    /// # let mut input: parkour::ArgsInput = todo!();
+    /// match input.parse_help_flag() {
+    ///     Ok(true) => println!("Usage: app [OPTIONS]"),
+    ///     Err(e) => match e.inner() {
+    ///         parkour::ErrorInner::HelpTopic(topic) => println!("Help for {}", topic),
+    ///         _ => {}
+    ///     },
+    ///     Ok(false) => {}
+    /// }
+    /// ```
+    fn parse_help_flag(&mut self) -> Result<bool, Error> {
+        if self.parse_long_flag("help") {
+            match self.expect_end_of_argument() {
+                Ok(()) => Ok(true),
+                Err(e) => match e.inner() {
+                    ErrorInner::UnexpectedValue { value } if value.is_empty() => Ok(true),
+                    ErrorInner::UnexpectedValue { value } => {
+                        Err(Error::help_topic(value))
+                    }
+                    _ => Err(e),
+                },
+            }
+        } else if self.parse_short_flag("h") {
+            self.expect_end_of_argument()?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
     /// Convenience function for parsing a (sub)command, i.e. an argument that
     /// doesn't start with a dash. Returns `true` if it succeeded.
     fn parse_command(&mut self, command: &str) -> bool;
 
-    /// Returns an error if the input is not yet empty.
+    /// Returns the next (sub)command, i.e. the current argument if it doesn't
+    /// start with a dash, without consuming it. This is useful for deciding
+    /// how to parse the rest of the input:
+    ///
+    /// ```no_run
+    /// # use parkour::prelude::*;
+    /// # let mut input: parkour::ArgsInput = todo!();
+    /// match input.peek_command() {
+    ///     Some("show") => { /* ... */ }
+    ///     Some(_) | None => { /* ... */ }
+    /// }
+    /// ```
+    fn peek_command(&self) -> Option<&str>;
+
+    /// Convenience function for parsing a chain of (sub)commands, e.g.
+    /// `remote add`. Returns `true` if all commands matched in order. The
+    /// commands are only consumed if the whole chain matches, so chains that
+    /// share a prefix can be tried one after the other:
+    ///
+    /// ```no_run
+    /// # use parkour::prelude::*;
+    /// # let mut input: parkour::ArgsInput = todo!();
+    /// if input.parse_command_chain(&["remote", "add"]) {
+    ///     // ...
+    /// } else if input.parse_command_chain(&["remote", "remove"]) {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// The default implementation can't look ahead, so it consumes the
+    /// commands of a matching prefix; implementors that can peek at the
+    /// following arguments should override it.
+    fn parse_command_chain(&mut self, commands: &[&str]) -> bool {
+        commands.iter().all(|command| self.parse_command(command))
+    }
+
+    /// Returns an error if the input is not yet empty. If the current argument
+    /// was partially consumed (e.g. `YES` in `-vYES` after parsing `-v`), an
+    /// "unexpected value" error is returned, otherwise an "unexpected argument"
+    /// error.
+    ///
+    /// If collecting unknown arguments is enabled (see
+    /// [`ArgsInput::set_collect_unknown`]), the current argument is stashed
+    /// instead of returning an error. In that case, the input may still contain
+    /// more arguments afterwards.
     fn expect_empty(&mut self) -> Result<(), Error>;
 
+    /// Returns an error if the input is not yet empty. Unlike
+    /// [`Parse::expect_empty`], this consumes all remaining arguments and
+    /// reports them in a single [`ErrorInner::UnexpectedArguments`] error.
+    fn save_and_report_unexpected(&mut self) -> Result<(), Error>;
+
     /// Returns an error if the current argument is only partially consumed.
     fn expect_end_of_argument(&mut self) -> Result<(), Error>;
+
+    /// Like [`Parse::expect_end_of_argument`], but the error mentions the flag
+    /// that was parsed before, e.g.
+    ///
+    /// ```text
+    /// unexpected value `yes`: in `--dry-run`
+    /// ```
+    #[inline]
+    fn expect_end_of_argument_for(&mut self, flag: &Flag<'_>) -> Result<(), Error> {
+        self.expect_end_of_argument()
+            .map_err(|e| e.chain(ErrorInner::InArgument(flag.first_to_string())))
+    }
+
+    /// If collecting all errors is enabled (see
+    /// [`ArgsInput::set_collect_all_errors`]), the error is stored and `Ok` is
+    /// returned, so parsing can continue. Otherwise, the error is returned.
+    ///
+    /// [`Error::no_value`], [`Error::early_exit`], [`Error::version`] and
+    /// [`Error::help_topic`] errors are never stored (see
+    /// [`Error::is_control_flow`]). [`ErrorInner::MultipleErrors`] errors
+    /// aren't stored either, since the errors they summarize were already
+    /// stored.
+    fn record_error(&mut self, error: Error) -> Result<(), Error>;
+
+    /// Returns the errors that were stored with [`Parse::record_error`], and
+    /// clears the list.
+    ///
+    /// ```no_run
+    /// # use parkour::prelude::*;
+    /// # #[derive(FromInput)]
+    /// # #[parkour(main)]
+    /// # struct Command {}
+    /// # let mut input: parkour::ArgsInput = todo!();
+    /// input.set_collect_all_errors(true);
+    /// if Command::from_input(&mut input, &()).is_err() {
+    ///     for error in input.take_errors() {
+    ///         eprintln!("{}", error);
+    ///     }
+    /// }
+    /// ```
+    fn take_errors(&mut self) -> Vec<Error>;
 }
 
 impl Parse for ArgsInput {
@@ -98,7 +562,7 @@ impl Parse for ArgsInput {
         &mut self,
         context: &V::Context,
     ) -> Result<V, Error> {
-        if V::allow_leading_dashes(&context) {
+        if V::allow_leading_dashes(context) || self.ignore_dashes() {
             let value = self.value_allows_leading_dashes().ok_or_else(Error::no_value)?;
             let result = V::from_input_value(value.as_str(), context)?;
             value.eat();
@@ -111,6 +575,78 @@ impl Parse for ArgsInput {
         }
     }
 
+    fn parse_named<'a, V: FromInputValue<'a>>(
+        &mut self,
+        flag: &Flag<'_>,
+        context: &V::Context,
+    ) -> Result<Option<V>, Error> {
+        if !Flag::from_input(self, flag)? {
+            return Ok(None);
+        }
+        match V::from_input_after_flag(self, context) {
+            Ok(value) => Ok(Some(value)),
+            Err(e) if e.is_no_value() => Err(Error::requires_value(flag)
+                .chain(ErrorInner::InArgument(flag.first_to_string()))),
+            Err(e) => Err(e.chain(ErrorInner::InArgument(flag.first_to_string()))),
+        }
+    }
+
+    fn parse_kv_list<'a, K, V>(
+        &mut self,
+        flag: &Flag<'_>,
+        pair_sep: char,
+        item_sep: char,
+    ) -> Result<Vec<(K, V)>, Error>
+    where
+        K: FromInputValue<'a>,
+        K::Context: Default,
+        V: FromInputValue<'a>,
+        V::Context: Default,
+    {
+        if !Flag::from_input(self, flag)? {
+            return Err(Error::no_value());
+        }
+        let in_argument = || ErrorInner::InArgument(flag.first_to_string());
+
+        let value = match self.value() {
+            Some(value) => value.eat().to_string(),
+            None => return Err(Error::requires_value(flag).chain(in_argument())),
+        };
+
+        value
+            .split(item_sep)
+            .enumerate()
+            .map(|(i, item)| {
+                let pair = match item.split_once(pair_sep) {
+                    Some((key, value)) => {
+                        K::from_input_value(key, &K::Context::default()).and_then(|key| {
+                            let value =
+                                V::from_input_value(value, &V::Context::default())?;
+                            Ok((key, value))
+                        })
+                    }
+                    None => Err(Error::custom(format!(
+                        "expected `key{}value`, got `{}`",
+                        pair_sep,
+                        item.escape_debug(),
+                    ))),
+                };
+                pair.map_err(|e| e.chain(ErrorInner::IncompleteValue(i)))
+            })
+            .collect::<Result<_, Error>>()
+            .map_err(|e| e.chain(in_argument()))
+    }
+
+    #[inline]
+    fn parse_value_ref(&mut self) -> Option<&str> {
+        self.value().map(|value| value.eat())
+    }
+
+    #[inline]
+    fn parse_value_allow_dashes(&mut self) -> Option<&str> {
+        self.value_allows_leading_dashes().map(|value| value.eat())
+    }
+
     #[inline]
     fn parse_short_flag(&mut self, flag: &str) -> bool {
         self.eat_one_dash(flag).is_some()
@@ -121,13 +657,56 @@ impl Parse for ArgsInput {
         self.eat_two_dashes(flag).is_some()
     }
 
+    #[inline]
+    fn parse_bool_flag(&mut self, flag: &Flag<'_>) -> bool {
+        matches!(Flag::from_input(self, flag), Ok(true))
+    }
+
+    #[inline]
+    fn next_is_flag(&self) -> bool {
+        self.can_parse_dash_argument()
+    }
+
+    fn parse_version_flag(&mut self, version: &str) -> Result<(), Error> {
+        if self.parse_long_flag("version") || self.parse_short_flag("V") {
+            self.expect_end_of_argument()?;
+            return Err(Error::version(version));
+        }
+        Ok(())
+    }
+
     #[inline]
     fn parse_command(&mut self, command: &str) -> bool {
         self.eat_no_dash(command).is_some()
     }
 
+    #[inline]
+    fn peek_command(&self) -> Option<&str> {
+        self.peek_no_dash()
+    }
+
+    fn parse_command_chain(&mut self, commands: &[&str]) -> bool {
+        let (first, rest) = match commands.split_first() {
+            Some(split) => split,
+            None => return true,
+        };
+        if self.peek_command() != Some(first)
+            || self.peek_following_args(rest.len()) != rest
+        {
+            return false;
+        }
+        commands.iter().all(|command| self.parse_command(command))
+    }
+
     fn expect_empty(&mut self) -> Result<(), Error> {
-        if !self.is_empty() {
+        if self.collect_unknown() {
+            self.stash_unknown();
+        } else if self.can_parse_value_no_whitespace() {
+            return Err(ErrorInner::UnexpectedValue {
+                value: self.bump_argument().unwrap().to_string(),
+            }
+            .into());
+        } else if !self.is_empty() {
             return Err(ErrorInner::UnexpectedArgument {
                 arg: self.bump_argument().unwrap().to_string(),
             }
@@ -136,6 +715,38 @@ impl Parse for ArgsInput {
         Ok(())
     }
 
+    fn save_and_report_unexpected(&mut self) -> Result<(), Error> {
+        let mut args = Vec::new();
+        while let Some(arg) = self.bump_argument_with_leading_dashes() {
+            args.push(arg);
+        }
+        if args.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::unexpected_arguments(args))
+        }
+    }
+
+    fn record_error(&mut self, error: Error) -> Result<(), Error> {
+        if !self.collect_all_errors() || error.is_control_flow() {
+            Err(error)
+        } else {
+            if !matches!(error.inner(), ErrorInner::MultipleErrors(_)) {
+                self.store_error(Box::new(error));
+            }
+            Ok(())
+        }
+    }
+
+    fn take_errors(&mut self) -> Vec<Error> {
+        // only `record_error` stores errors, so they are all parkour errors
+        self.take_stored_errors()
+            .into_iter()
+            .filter_map(|e| e.downcast::<Error>().ok())
+            .map(|e| *e)
+            .collect()
+    }
+
     fn expect_end_of_argument(&mut self) -> Result<(), Error> {
         if self.can_parse_value_no_whitespace() {
             return Err(ErrorInner::UnexpectedValue {
@@ -146,3 +757,33 @@ impl Parse for ArgsInput {
         Ok(())
     }
 }
+
+/// Wrapper that strips the quotes of a value before parsing it, see
+/// [`Parse::parse_value_unquoted`]
+struct Unquoted<V>(V);
+
+impl<'a, V: FromInputValue<'a>> FromInputValue<'a> for Unquoted<V> {
+    type Context = V::Context;
+
+    fn from_input_value(value: &str, context: &Self::Context) -> Result<Self, Error> {
+        V::from_input_value(unquote(value), context).map(Unquoted)
+    }
+
+    fn allow_leading_dashes(context: &Self::Context) -> bool {
+        V::allow_leading_dashes(context)
+    }
+
+    fn possible_values(context: &Self::Context) -> Option<PossibleValues> {
+        V::possible_values(context)
+    }
+}
+
+/// Strips matching `"` or `'` quotes surrounding the string, if present.
+fn unquote(s: &str) -> &str {
+    for quote in ['"', '\''] {
+        if s.len() >= 2 && s.starts_with(quote) && s.ends_with(quote) {
+            return &s[1..s.len() - 1];
+        }
+    }
+    s
+}
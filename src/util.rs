@@ -1,5 +1,6 @@
 //! Several utility types.
 
+use std::borrow::Cow;
 use std::fmt;
 use std::fmt::Write as _;
 
@@ -27,9 +28,61 @@ pub enum Flag<'a> {
     LongShort(&'a str, &'a str),
     /// A flag with multiple aliases
     Many(Vec<Flag<'a>>),
+    /// A long flag within a namespace, e.g. `Prefixed("feature", "x")` matches
+    /// `--feature-x`.
+    Prefixed(&'a str, &'a str),
 }
 
-impl Flag<'_> {
+impl<'a> Flag<'a> {
+    /// Returns all long aliases of the flag (without the leading dashes).
+    ///
+    /// ```
+    /// # use parkour::util::Flag;
+    /// assert_eq!(Flag::LongShort("color", "c").long_aliases(), ["color"]);
+    /// ```
+    pub fn long_aliases(&self) -> Vec<Cow<'a, str>> {
+        match self {
+            &Flag::Short(_) => vec![],
+            &Flag::Long(l) | &Flag::LongShort(l, _) => vec![Cow::Borrowed(l)],
+            Flag::Many(v) => v.iter().flat_map(Flag::long_aliases).collect(),
+            &Flag::Prefixed(p, l) => vec![Cow::Owned(format!("{}-{}", p, l))],
+        }
+    }
+
+    /// Returns all short aliases of the flag (without the leading dash).
+    ///
+    /// ```
+    /// # use parkour::util::Flag;
+    /// assert_eq!(Flag::LongShort("color", "c").short_aliases(), ["c"]);
+    /// ```
+    pub fn short_aliases(&self) -> Vec<&'a str> {
+        match self {
+            &Flag::Short(s) | &Flag::LongShort(_, s) => vec![s],
+            &Flag::Long(_) | &Flag::Prefixed(..) => vec![],
+            Flag::Many(v) => v.iter().flat_map(Flag::short_aliases).collect(),
+        }
+    }
+
+    /// Returns `true` if the token (including the leading dashes) is one of the
+    /// flag's aliases, e.g. `--color` or `-c`. This doesn't consume any input.
+    ///
+    /// ```
+    /// # use parkour::util::Flag;
+    /// let flag = Flag::LongShort("color", "c");
+    /// assert!(flag.matches("--color"));
+    /// assert!(flag.matches("-c"));
+    /// assert!(!flag.matches("--c"));
+    /// ```
+    pub fn matches(&self, token: &str) -> bool {
+        if let Some(long) = token.strip_prefix("--") {
+            self.long_aliases().iter().any(|l| l == long)
+        } else if let Some(short) = token.strip_prefix('-') {
+            self.short_aliases().contains(&short)
+        } else {
+            false
+        }
+    }
+
     /// Returns the first alias of the flag as a [String].
     pub fn first_to_string(&self) -> String {
         match self {
@@ -37,11 +90,12 @@ impl Flag<'_> {
             &Flag::Long(l) => format!("--{}", l),
             &Flag::LongShort(l, _) => format!("--{}", l),
             Flag::Many(v) => v[0].first_to_string(),
+            &Flag::Prefixed(p, l) => format!("--{}-{}", p, l),
         }
     }
 
     /// Parses a flag from a [`Parse`] instance.
-    pub fn from_input<'a>(input: &mut ArgsInput, context: &Flag<'a>) -> ApplyResult {
+    pub fn from_input<'b>(input: &mut ArgsInput, context: &Flag<'b>) -> ApplyResult {
         Ok(match context {
             &Flag::Short(f) => input.parse_short_flag(f),
             &Flag::Long(f) => input.parse_long_flag(f),
@@ -51,6 +105,27 @@ impl Flag<'_> {
             Flag::Many(flags) => {
                 flags.iter().any(|flag| matches!(Self::from_input(input, flag), Ok(true)))
             }
+            &Flag::Prefixed(prefix, name) => {
+                let separators = input.value_separators().to_vec();
+                match input.two_dashes() {
+                    Some(part) => {
+                        let matches = part
+                            .as_str()
+                            .strip_prefix(prefix)
+                            .and_then(|s| s.strip_prefix('-'))
+                            .and_then(|s| s.strip_prefix(name))
+                            .is_some_and(|rest| {
+                                rest.is_empty() || rest.starts_with(&separators[..])
+                            });
+
+                        if matches {
+                            part.take(prefix.len() + 1 + name.len()).eat();
+                        }
+                        matches
+                    }
+                    None => false,
+                }
+            }
         })
     }
 }
@@ -61,6 +136,7 @@ impl fmt::Display for Flag<'_> {
             Flag::Short(s) => write!(f, "-{}", s),
             Flag::Long(l) => write!(f, "--{}", l),
             Flag::LongShort(l, s) => write!(f, "--{},-{}", l, s),
+            Flag::Prefixed(p, l) => write!(f, "--{}-{}", p, l),
             Flag::Many(v) => {
                 for (i, flag) in v.iter().enumerate() {
                     if i > 0 {
@@ -96,6 +172,38 @@ impl<'a, C: Default> From<Flag<'a>> for ArgCtx<'a, C> {
     }
 }
 
+/// The parsing context for a boolean that is controlled by two flags, e.g.
+/// `--enable-cache` and `--disable-cache`. The first flag sets the value to
+/// `true`, the second one to `false`.
+///
+/// ### Example
+///
+/// ```no_run
+/// # use parkour::prelude::*;
+/// use parkour::actions::Set;
+/// use parkour::util::EnablePairCtx;
+///
+/// # let mut input: parkour::ArgsInput = todo!();
+/// let mut cache = true;
+/// let ctx = EnablePairCtx::new(Flag::Long("enable-cache"), Flag::Long("disable-cache"));
+/// Set(&mut cache).apply(&mut input, &ctx)?;
+/// # Ok::<(), parkour::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct EnablePairCtx<'a> {
+    /// The flag that sets the value to `true`
+    pub enable: Flag<'a>,
+    /// The flag that sets the value to `false`
+    pub disable: Flag<'a>,
+}
+
+impl<'a> EnablePairCtx<'a> {
+    /// Creates a new `EnablePairCtx` instance
+    pub fn new(enable: Flag<'a>, disable: Flag<'a>) -> Self {
+        Self { enable, disable }
+    }
+}
+
 /// The parsing context for a positional argument.
 #[derive(Debug, Clone)]
 pub struct PosCtx<'a, C> {
@@ -103,17 +211,23 @@ pub struct PosCtx<'a, C> {
     pub name: &'a str,
     /// The context for the argument value
     pub inner: C,
+    /// How often the positional argument may occur. This is only used by
+    /// actions that can store multiple values, e.g. [`SetPositional`] with a
+    /// [`Vec`]. The default is `None`, which means there's no limit.
+    ///
+    /// [`SetPositional`]: crate::actions::SetPositional
+    pub max_occurrences: Option<u32>,
 }
 
 impl<'a, C> PosCtx<'a, C> {
     /// Creates a new `PosCtx` instance
     pub fn new(name: &'a str, inner: C) -> Self {
-        Self { name, inner }
+        Self { name, inner, max_occurrences: None }
     }
 }
 
 impl<'a, C: Default> From<&'a str> for PosCtx<'a, C> {
     fn from(name: &'a str) -> Self {
-        PosCtx { name, inner: C::default() }
+        PosCtx { name, inner: C::default(), max_occurrences: None }
     }
 }
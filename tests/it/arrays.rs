@@ -0,0 +1,64 @@
+use std::error::Error as _;
+
+use parkour::prelude::*;
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main)]
+struct Command {
+    #[arg(long)]
+    rgb: Option<[u8; 3]>,
+    #[arg(long)]
+    size: [u32; 2],
+}
+
+macro_rules! ok {
+    ($s:literal, $v:expr) => {
+        assert_parse!(Command, $s, $v)
+    };
+}
+macro_rules! err {
+    ($s:literal, $e:literal) => {
+        assert_parse!(Command, $s, $e)
+    };
+}
+
+#[test]
+fn successes() {
+    ok!("$ --size 1 2", Command { rgb: None, size: [1, 2] });
+    ok!("$ --size=1,2", Command { rgb: None, size: [1, 2] });
+    ok!("$ --size 1,2 --rgb 1 2 3", Command { rgb: Some([1, 2, 3]), size: [1, 2] });
+    ok!("$ --rgb=1,2,3 --size 3 4", Command { rgb: Some([1, 2, 3]), size: [3, 4] });
+    ok!("$ --rgb 1,2,3 --size 3 4", Command { rgb: Some([1, 2, 3]), size: [3, 4] });
+}
+
+#[test]
+fn failures() {
+    err!("$ --rgb 1 2 3", "required --size was not provided");
+    err!("$ --size 1", "wrong number of values, expected 2, got 1: in `--size`");
+    err!(
+        "$ --size 1 --rgb 1 2 3",
+        "wrong number of values, expected 2, got 1: in `--size`"
+    );
+    err!("$ --size=1,2,3", "wrong number of values, expected 2, got 3: in `--size`");
+    err!("$ --size 1 2 3", "unexpected argument `3`");
+    err!(
+        "$ --size 1 2 --rgb 1,x,3",
+        "invalid digit found in string: in `--rgb`: missing part 1 of value"
+    );
+    err!(
+        "$ --size 1 2 --rgb 1 2 x",
+        "invalid digit found in string: in `--rgb`: missing part 2 of value"
+    );
+}
+
+#[test]
+fn prelude_contexts() {
+    let mut input = ArgsInput::from("1:2:3 12:30");
+    let ctx = ArrayCtx::new(Some(':'), NumberCtx::default());
+    let array: [u8; 3] = input.parse_value(&ctx).unwrap();
+    assert_eq!(array, [1, 2, 3]);
+
+    let ctx = TupleCtx::new(':', (NumberCtx::default(), NumberCtx::default()));
+    let time: (u8, u8) = input.parse_value(&ctx).unwrap();
+    assert_eq!(time, (12, 30));
+}
@@ -0,0 +1,92 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use parkour::prelude::*;
+
+static PRINTED: AtomicBool = AtomicBool::new(false);
+
+fn print_help() {
+    PRINTED.store(true, Ordering::SeqCst);
+}
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main, auto_help)]
+struct Command {
+    #[arg(long, short)]
+    verbose: bool,
+}
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main, auto_help = print_help)]
+struct Custom {
+    #[arg(long)]
+    dry_run: bool,
+}
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main, auto_help)]
+enum Service {
+    Start,
+    Stop,
+}
+
+#[test]
+fn generated_help() {
+    for &s in &["$ --help", "$ -h", "$ --verbose --help"] {
+        let e = Command::from_input(&mut ArgsInput::from(s), &()).unwrap_err();
+        assert!(e.is_early_exit());
+    }
+
+    let e = Command::from_input(&mut ArgsInput::from("$ -- --help"), &()).unwrap_err();
+    assert_eq!(e.to_string(), "unexpected argument `--help`");
+
+    let e = Command::from_input(&mut ArgsInput::from("$ --help=net"), &()).unwrap_err();
+    assert_eq!(e.inner(), &parkour::ErrorInner::HelpTopic("net".into()));
+
+    let e = Command::from_input(&mut ArgsInput::from("$ -h=net"), &()).unwrap_err();
+    assert_eq!(e.to_string(), "unexpected value `net`");
+}
+
+#[test]
+fn custom_printer() {
+    let e = Custom::from_input(&mut ArgsInput::from("$ -h"), &()).unwrap_err();
+    assert!(e.is_early_exit());
+    assert!(PRINTED.load(Ordering::SeqCst));
+}
+
+#[test]
+fn enum_help() {
+    let e = Service::from_input(&mut ArgsInput::from("$ --help"), &()).unwrap_err();
+    assert!(e.is_early_exit());
+
+    let action = Service::from_input(&mut ArgsInput::from("$ stop"), &()).unwrap();
+    assert_eq!(action, Service::Stop);
+}
+
+#[test]
+fn parse_or_help() {
+    use std::cell::Cell;
+    use std::error::Error as _;
+
+    let called = Cell::new(false);
+    let mut input = ArgsInput::from("$ --verbose");
+    let c: Command = input.parse_or_help(&(), || called.set(true)).unwrap();
+    assert_eq!(c, Command { verbose: true });
+    assert!(!called.get());
+
+    let mut input = ArgsInput::from("$ --quiet");
+    let e = input.parse_or_help::<Command>(&(), || called.set(true)).unwrap_err();
+    assert!(called.get());
+    assert!(e.is_early_exit());
+    assert_eq!(e.source().unwrap().to_string(), "unexpected argument `quiet`");
+
+    called.set(false);
+    let mut input = ArgsInput::from("$ --help");
+    let e = input.parse_or_help::<Command>(&(), || called.set(true)).unwrap_err();
+    assert!(e.is_early_exit());
+    assert!(!called.get());
+
+    let mut input = ArgsInput::from("-x");
+    let e = input.parse_or_help::<usize>(&Flag::Short("n").into(), || called.set(true));
+    assert!(e.unwrap_err().is_no_value());
+    assert!(!called.get());
+}
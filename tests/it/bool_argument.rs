@@ -30,8 +30,8 @@ fn successes() {
 #[test]
 fn failures() {
     err!("$ -dYES", "unexpected value `YES`");
-    err!("$ -d=yes", "unexpected value `yes`");
-    err!("$ --dry-run=", "unexpected value ``");
+    err!("$ -d=yes", "unexpected value `yes`: in `--dry-run`");
+    err!("$ --dry-run=", "unexpected value ``: in `--dry-run`");
     err!("$ --dry-run yes", "unexpected argument `yes`");
     err!("$ dry-run", "unexpected argument `dry-run`");
     err!(
@@ -39,3 +39,111 @@ fn failures() {
         "--dry-run was used too often, it can be used at most 1 times"
     );
 }
+
+#[test]
+fn enable_pair() {
+    use parkour::actions::Set;
+    use parkour::util::EnablePairCtx;
+
+    let ctx = EnablePairCtx::new(Flag::Long("enable-cache"), Flag::Long("disable-cache"));
+    let mut input = ArgsInput::from("--disable-cache --enable-cache --disable-cache");
+
+    let mut cache = true;
+    assert!(Set(&mut cache).apply(&mut input, &ctx).unwrap());
+    assert!(!cache);
+    assert!(Set(&mut cache).apply(&mut input, &ctx).unwrap());
+    assert!(cache);
+    assert!(Set(&mut cache).apply(&mut input, &ctx).unwrap());
+    assert!(!cache);
+    assert!(!Set(&mut cache).apply(&mut input, &ctx).unwrap());
+}
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main)]
+struct Flags {
+    #[arg(short)]
+    verbose: bool,
+    #[arg(short)]
+    x: bool,
+}
+
+#[test]
+fn combined_short_flags() {
+    assert_parse!(Flags, "$ -vx", Flags { verbose: true, x: true });
+    assert_parse!(Flags, "$ -xv", Flags { verbose: true, x: true });
+    assert_parse!(Flags, "$ -v1", "unexpected value `1`");
+    assert_parse!(Flags, "$ -vx=1", "unexpected value `1`: in `-x`");
+    assert_parse!(
+        Flags,
+        "$ -vv",
+        "-v was used too often, it can be used at most 1 times"
+    );
+}
+
+#[test]
+fn toggle() {
+    let mut input = ArgsInput::from("--color --no-color -c --no-c -x");
+    assert_eq!(input.parse_toggle("color", Some("c")), Some(true));
+    assert_eq!(input.parse_toggle("color", Some("c")), Some(false));
+    assert_eq!(input.parse_toggle("color", Some("c")), Some(true));
+    assert_eq!(input.parse_toggle("color", Some("c")), None);
+    assert!(input.parse_long_flag("no-c"));
+    assert_eq!(input.parse_toggle("color", None), None);
+}
+
+#[test]
+fn bool_flag() {
+    let flag = Flag::LongShort("verbose", "v");
+    let mut input = ArgsInput::from("-v --verbose --v -x");
+    assert!(input.parse_bool_flag(&flag));
+    assert!(input.parse_bool_flag(&flag));
+    assert!(!input.parse_bool_flag(&flag));
+    assert!(input.parse_long_flag("v"));
+    assert!(!input.parse_bool_flag(&Flag::Many(vec![flag, Flag::Long("x")])));
+    assert!(input.parse_bool_flag(&Flag::Short("x")));
+}
+
+#[test]
+fn count_flag() {
+    let flag = Flag::LongShort("verbose", "v");
+    let mut input = ArgsInput::from("-v -v --verbose -x -vv");
+    assert_eq!(input.count_flag(&flag), 3);
+    assert_eq!(input.count_flag(&flag), 0);
+    assert!(input.parse_short_flag("x"));
+    assert_eq!(input.count_flag(&flag), 2);
+    assert!(input.is_empty());
+}
+
+#[test]
+fn parse_flags() {
+    let (mut verbose, mut quiet, mut force) = (false, false, false);
+    let mut input = ArgsInput::from("-q --verbose -x");
+    let v = Flag::LongShort("verbose", "v");
+    let q = Flag::LongShort("quiet", "q");
+    let f = Flag::LongShort("force", "f");
+    let mut flags = [(&v, &mut verbose), (&q, &mut quiet), (&f, &mut force)];
+    assert!(input.parse_flags(&mut flags).unwrap());
+    assert!(input.parse_flags(&mut flags).unwrap());
+    assert!(!input.parse_flags(&mut flags).unwrap());
+    assert!(input.parse_short_flag("x"));
+    assert!(verbose && quiet && !force);
+}
+
+#[test]
+fn enable_disable() {
+    use parkour::impls::{EnableDisable, Toggle};
+
+    let ctx = EnableDisable::new(8080u16);
+    let mut input = ArgsInput::from("on OFF maybe");
+    assert_eq!(input.parse_value::<Toggle<_>>(&ctx).unwrap(), Toggle(Some(8080)));
+    assert_eq!(input.parse_value::<Toggle<_>>(&ctx).unwrap(), Toggle(None));
+    assert_eq!(
+        input.parse_value::<Toggle<u16>>(&ctx).unwrap_err().to_string(),
+        "unexpected value `maybe`, expected `on` or `off`"
+    );
+
+    let ctx = EnableDisable { enable: "enabled", disable: "disabled", value: "gzip" };
+    let mut input = ArgsInput::from("enabled on");
+    assert_eq!(input.parse_value::<Toggle<_>>(&ctx).unwrap(), Toggle(Some("gzip")));
+    assert!(input.parse_value::<Toggle<&str>>(&ctx).is_err());
+}
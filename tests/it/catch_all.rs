@@ -0,0 +1,42 @@
+use std::error::Error as _;
+
+use parkour::prelude::*;
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main)]
+struct Command {
+    #[arg(long)]
+    format: Option<Format>,
+}
+
+#[derive(FromInputValue, Debug, PartialEq)]
+enum Format {
+    Json,
+    Yaml,
+    #[parkour(catch_all)]
+    Other(String),
+}
+
+macro_rules! ok {
+    ($s:literal, $v:expr) => {
+        assert_parse!(Command, $s, $v)
+    };
+}
+macro_rules! err {
+    ($s:literal, $e:literal) => {
+        assert_parse!(Command, $s, $e)
+    };
+}
+
+#[test]
+fn successes() {
+    ok!("$ --format json", Command { format: Some(Format::Json) });
+    ok!("$ --format YAML", Command { format: Some(Format::Yaml) });
+    ok!("$ --format toml", Command { format: Some(Format::Other("toml".into())) });
+    ok!("$ --format=", Command { format: Some(Format::Other("".into())) });
+}
+
+#[test]
+fn failures() {
+    err!("$ --format", "argument --format requires a value: in `--format`");
+}
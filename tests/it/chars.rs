@@ -0,0 +1,24 @@
+use std::collections::HashSet;
+
+use parkour::impls::{Chars, CharsCtx};
+use parkour::prelude::*;
+
+#[test]
+fn chars() {
+    let mut input = ArgsInput::from("rwx");
+    let perms: Chars<Vec<char>> = input.parse_value(&CharsCtx::default()).unwrap();
+    assert_eq!(perms.into_inner(), vec!['r', 'w', 'x']);
+
+    let mut input = ArgsInput::from("xrx");
+    let ctx = CharsCtx { allowed: Some("rwx") };
+    let perms: Chars<HashSet<char>> = input.parse_value(&ctx).unwrap();
+    assert_eq!(perms.0, ['r', 'x'].iter().copied().collect());
+}
+
+#[test]
+fn disallowed_char() {
+    let mut input = ArgsInput::from("rwz");
+    let ctx = CharsCtx { allowed: Some("rwx") };
+    let e = input.parse_value::<Chars<Vec<char>>>(&ctx).unwrap_err();
+    assert_eq!(e.to_string(), "unexpected value `z`, expected `r`, `w` or `x`");
+}
@@ -0,0 +1,84 @@
+use std::error::Error as _;
+
+use parkour::prelude::*;
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main)]
+enum Command {
+    Serve {
+        #[arg(long)]
+        port: u16,
+        #[arg(long)]
+        host: String,
+    },
+    Stop,
+}
+
+macro_rules! ok {
+    ($s:literal, $v:expr) => {
+        assert_parse!(Command, $s, $v)
+    };
+}
+macro_rules! err {
+    ($s:literal, $e:literal) => {
+        assert_parse!(Command, $s, $e)
+    };
+}
+
+#[test]
+fn successes() {
+    ok!("$ serve --port 80 --host x", Command::Serve { port: 80, host: "x".to_string() });
+    ok!("$ serve --host=x --port=80", Command::Serve { port: 80, host: "x".to_string() });
+    ok!("$ stop", Command::Stop);
+}
+
+#[test]
+fn failures() {
+    err!("$ serve --port 80", "required --host was not provided");
+    err!("$ serve --port x --host y", "invalid digit found in string: in `--port`");
+    err!("$ serve --port 80 --host y z", "unexpected argument `z`");
+}
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main, default_subcommand = "show")]
+enum Config {
+    Show,
+    Set {
+        #[arg(long)]
+        key: String,
+        #[arg(long)]
+        value: String,
+    },
+}
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main, default_subcommand = "serve")]
+enum Server {
+    Serve {
+        #[arg(long)]
+        port: u16,
+        #[arg(long)]
+        host: String,
+    },
+    Stop,
+}
+
+#[test]
+fn default_subcommand() {
+    assert_parse!(Config, "$", Config::Show);
+    assert_parse!(Config, "$ show", Config::Show);
+    assert_parse!(
+        Config,
+        "$ set --key a --value b",
+        Config::Set { key: "a".to_string(), value: "b".to_string() }
+    );
+    assert_parse!(Config, "$ x", "unexpected argument `x`");
+
+    assert_parse!(
+        Server,
+        "$ --port 80 --host x",
+        Server::Serve { port: 80, host: "x".to_string() }
+    );
+    assert_parse!(Server, "$ stop", Server::Stop);
+    assert_parse!(Server, "$", "required --port was not provided");
+}
@@ -0,0 +1,121 @@
+use std::error::Error as _;
+
+use parkour::prelude::*;
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(group)]
+struct CommonOpts {
+    #[arg(long, short)]
+    verbose: bool,
+    #[arg(long)]
+    config: Option<String>,
+}
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(group)]
+struct Output {
+    #[arg(long)]
+    out: String,
+    #[arg(flatten)]
+    common: CommonOpts,
+}
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main)]
+struct Command {
+    #[arg(flatten)]
+    common: CommonOpts,
+    #[arg(long)]
+    jobs: Option<u8>,
+}
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main)]
+struct Build {
+    #[arg(flatten)]
+    output: Output,
+}
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main, auto_help)]
+struct Help {
+    #[arg(long)]
+    jobs: Option<u8>,
+    #[arg(flatten)]
+    output: Output,
+}
+
+#[test]
+fn flatten() {
+    let common = |verbose, config: Option<&str>| CommonOpts {
+        verbose,
+        config: config.map(ToString::to_string),
+    };
+
+    assert_parse!(Command, "$", Command { common: common(false, None), jobs: None });
+    assert_parse!(
+        Command,
+        "$ --verbose --jobs 2",
+        Command { common: common(true, None), jobs: Some(2) }
+    );
+    assert_parse!(
+        Command,
+        "$ --jobs=2 --config x -v",
+        Command { common: common(true, Some("x")), jobs: Some(2) }
+    );
+    assert_parse!(
+        Command,
+        "$ -v -v",
+        "--verbose was used too often, it can be used at most 1 times"
+    );
+    assert_parse!(Command, "$ --quiet", "unexpected argument `quiet`");
+}
+
+#[test]
+fn nested_flatten() {
+    assert_parse!(
+        Build,
+        "$ -v --out x",
+        Build {
+            output: Output {
+                out: "x".to_string(),
+                common: CommonOpts { verbose: true, config: None },
+            }
+        }
+    );
+    assert_parse!(Build, "$ -v", "required --out was not provided");
+}
+
+#[test]
+fn help_options() {
+    assert_eq!(
+        <CommonOpts as parkour::ArgGroup>::help_options(),
+        ["  --verbose, -v", "  --config"]
+    );
+    assert_eq!(
+        <Output as parkour::ArgGroup>::help_options(),
+        ["  --out", "  --verbose, -v", "  --config"]
+    );
+}
+
+#[test]
+fn help_includes_flattened_options() {
+    // the help message is printed to stdout, so it's tested in a child process
+    if std::env::var_os("PARKOUR_PRINT_HELP").is_some() {
+        let e = Help::from_input(&mut ArgsInput::from("$ --help"), &()).unwrap_err();
+        assert!(e.is_early_exit());
+        return;
+    }
+
+    let output = std::process::Command::new(std::env::current_exe().unwrap())
+        .args(["flatten::help_includes_flattened_options", "--exact", "--nocapture"])
+        .env("PARKOUR_PRINT_HELP", "1")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains(
+        "Usage: parkour [OPTIONS]\n\nOptions:\n  --jobs\n  --out\n  --verbose, -v\n  \
+         --config\n  -h, --help\n"
+    ));
+}
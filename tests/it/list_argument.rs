@@ -0,0 +1,110 @@
+use std::collections::BTreeSet;
+use std::error::Error as _;
+
+use parkour::prelude::*;
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main)]
+struct Command {
+    #[arg(long, short)]
+    tag: Vec<String>,
+    #[arg(long)]
+    id: BTreeSet<u32>,
+}
+
+macro_rules! ok {
+    ($s:literal, $v:expr) => {
+        assert_parse!(Command, $s, $v)
+    };
+}
+macro_rules! err {
+    ($s:literal, $e:literal) => {
+        assert_parse!(Command, $s, $e)
+    };
+}
+
+fn tags(tags: &[&str]) -> Vec<String> {
+    tags.iter().map(|&s| s.to_string()).collect()
+}
+
+#[test]
+fn successes() {
+    ok!("$", Command { tag: vec![], id: BTreeSet::new() });
+    ok!("$ --tag a --tag b", Command { tag: tags(&["a", "b"]), id: BTreeSet::new() });
+    ok!("$ --tag=a,b -tc", Command { tag: tags(&["a", "b", "c"]), id: BTreeSet::new() });
+    ok!(
+        "$ --id 3 --id 1,2 --id 3",
+        Command { tag: vec![], id: vec![1, 2, 3].into_iter().collect() }
+    );
+}
+
+#[test]
+fn failures() {
+    err!("$ --tag", "argument --tag requires a value: in `--tag`");
+    err!("$ -t --id 1", "argument --tag requires a value: in `--tag`");
+    err!("$ --id x", "invalid digit found in string: in `--id`: missing part 0 of value");
+}
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main)]
+struct Slices {
+    #[arg(short)]
+    f: Box<[u8]>,
+    #[arg(short)]
+    g: std::rc::Rc<[u8]>,
+}
+
+#[test]
+fn boxed_slices() {
+    assert_parse!(
+        Slices,
+        "$ -f 1,2,3 -g 4 -f 5",
+        Slices { f: vec![1, 2, 3, 5].into(), g: vec![4].into() }
+    );
+    assert_parse!(Slices, "$", Slices { f: Box::new([]), g: vec![].into() });
+
+    let mut input = ArgsInput::from("-f 1,2");
+    let f: Box<[u8]> = input.parse(&Flag::Short("f").into()).unwrap();
+    assert_eq!(&*f, &[1, 2]);
+}
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main)]
+struct OptionalList {
+    #[arg(long)]
+    tag: Option<Vec<String>>,
+}
+
+#[test]
+fn optional_list() {
+    assert_parse!(OptionalList, "$", OptionalList { tag: None });
+    assert_parse!(OptionalList, "$ --tag a", OptionalList { tag: Some(tags(&["a"])) });
+    assert_parse!(
+        OptionalList,
+        "$ --tag a --tag b,c",
+        OptionalList { tag: Some(tags(&["a", "b", "c"])) }
+    );
+}
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main)]
+struct Delimited {
+    #[arg(long, value_delimiter = ';')]
+    path: Vec<String>,
+    #[arg(long, value_delimiter = ':')]
+    pair: Option<[u8; 2]>,
+}
+
+#[test]
+fn value_delimiter() {
+    assert_parse!(
+        Delimited,
+        "$ --path a;b;c --pair 1:2",
+        Delimited { path: tags(&["a", "b", "c"]), pair: Some([1, 2]) }
+    );
+    assert_parse!(
+        Delimited,
+        "$ --path a,b",
+        Delimited { path: tags(&["a,b"]), pair: None }
+    );
+}
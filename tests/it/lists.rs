@@ -0,0 +1,147 @@
+use parkour::impls::Separator;
+use parkour::prelude::*;
+
+fn parse(s: &'static str, separator: Separator) -> parkour::Result<Vec<u32>> {
+    let mut input = ArgsInput::from(s);
+    let ctx = ListCtx { separator, ..Flag::Short("f").into() };
+    let list = input.parse(&ctx)?;
+    input.expect_empty()?;
+    Ok(list)
+}
+
+#[test]
+fn separator_comma() {
+    assert_eq!(parse("-f 1,2", Separator::Comma).unwrap(), vec![1, 2]);
+    assert_eq!(parse("-f=1,2", Separator::Comma).unwrap(), vec![1, 2]);
+    assert_eq!(
+        parse("-f 1 2", Separator::Comma).unwrap_err().to_string(),
+        "unexpected argument `2`"
+    );
+}
+
+#[test]
+fn separator_whitespace() {
+    assert_eq!(parse("-f 1 2", Separator::Whitespace).unwrap(), vec![1, 2]);
+    assert_eq!(parse("-f=1", Separator::Whitespace).unwrap(), vec![1]);
+    assert!(parse("-f 1,2", Separator::Whitespace).is_err());
+}
+
+#[test]
+fn separator_either() {
+    assert_eq!(parse("-f 1,2", Separator::Either).unwrap(), vec![1, 2]);
+    assert_eq!(parse("-f=1,2", Separator::Either).unwrap(), vec![1, 2]);
+    assert_eq!(parse("-f 1 2 3", Separator::Either).unwrap(), vec![1, 2, 3]);
+    assert_eq!(parse("-f1,2", Separator::Either).unwrap(), vec![1, 2]);
+}
+
+#[test]
+fn separator_custom() {
+    assert_eq!(parse("-f 1:2:3", Separator::Custom(':')).unwrap(), vec![1, 2, 3]);
+    assert!(parse("-f 1,2", Separator::Custom(':')).is_err());
+}
+
+#[test]
+fn terminator() {
+    let ctx = ListCtx {
+        separator: Separator::Whitespace,
+        terminator: Some("end"),
+        ..Flag::Short("f").into()
+    };
+    let mut input = ArgsInput::from("-f a b end -g");
+    let list: Vec<String> = input.parse(&ctx).unwrap();
+    assert_eq!(list, vec!["a", "b"]);
+    assert!(input.parse_short_flag("g"));
+    assert!(input.is_empty());
+
+    let ctx = ListCtx {
+        separator: Separator::Whitespace,
+        terminator: Some("--"),
+        ..Flag::Short("f").into()
+    };
+    let mut input = ArgsInput::from("-f -1 -2 -- -3");
+    let list: Vec<i32> = input.parse(&ctx).unwrap();
+    assert_eq!(list, vec![-1, -2]);
+    assert_eq!(input.bump_argument(), Some("3"));
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn array_vec() {
+    use arrayvec::ArrayVec;
+
+    let ctx: ListCtx<'_, NumberCtx<u8>> = Flag::Short("f").into();
+    let mut input = ArgsInput::from("-f 1,2,3");
+    let values: ArrayVec<u8, 3> = input.parse(&ctx).unwrap();
+    assert_eq!(values.as_slice(), [1, 2, 3]);
+
+    let mut input = ArgsInput::from("-f 1,2,3,4");
+    let e = input.parse::<ArrayVec<u8, 3>>(&ctx).unwrap_err();
+    assert_eq!(e.to_string(), "too many values, expected at most 3, got 4");
+
+    let ctx = ListCtx { separator: Separator::Whitespace, ..ctx };
+    let mut input = ArgsInput::from("-f 1 2 3 4");
+    assert!(input.parse::<ArrayVec<u8, 3>>(&ctx).is_err());
+}
+
+#[test]
+fn path_list() {
+    use parkour::impls::{PathList, PathListCtx};
+    use std::path::PathBuf;
+
+    let ctx = PathListCtx { separator: ':', ..Default::default() };
+    let mut input = ArgsInput::from("--include a:b/c:d");
+    assert!(input.parse_long_flag("include"));
+    let PathList(paths) = input.parse_value(&ctx).unwrap();
+    assert_eq!(paths, ["a", "b/c", "d"].iter().map(PathBuf::from).collect::<Vec<_>>());
+
+    let ctx = PathListCtx { inner: StringCtx::new(1, usize::MAX), ..ctx };
+    let mut input = ArgsInput::from("a::b");
+    assert_eq!(
+        input.parse_value::<PathList>(&ctx).unwrap_err().to_string(),
+        "unexpected value `string with length 0`, expected non-empty path"
+    );
+
+    #[cfg(unix)]
+    {
+        let mut input = ArgsInput::from("a:b:c");
+        let paths: PathList = input.parse_value(&PathListCtx::default()).unwrap();
+        assert_eq!(paths.into_paths().len(), 3);
+    }
+}
+
+#[test]
+fn append_map() {
+    use std::collections::HashMap;
+
+    let ctx = ListCtx {
+        inner: TupleCtx::new('=', (StringCtx::default(), NumberCtx::default())),
+        ..Flag::Short("e").into()
+    };
+    let mut input = ArgsInput::from("-e A=1 -e B=2,A=3 -e C");
+    let mut env: HashMap<String, u32> = HashMap::new();
+    assert!(Append(&mut env).apply(&mut input, &ctx).unwrap());
+    assert!(Append(&mut env).apply(&mut input, &ctx).unwrap());
+    assert_eq!(env.len(), 2);
+    assert_eq!(env["A"], 3);
+    assert_eq!(env["B"], 2);
+
+    let e = Append(&mut env).apply(&mut input, &ctx).unwrap_err();
+    assert_eq!(e.to_string(), "missing part 2 of value");
+    assert!(!Append(&mut env).apply(&mut ArgsInput::from("-f A=1"), &ctx).unwrap());
+}
+
+#[test]
+fn max_bytes() {
+    let ctx = ListCtx { max_bytes: 5, ..Flag::Short("f").into() };
+    let list: Vec<String> = ArgsInput::from("-f ab,cd").parse(&ctx).unwrap();
+    assert_eq!(list, vec!["ab", "cd"]);
+    let e = ArgsInput::from("-f ab,cd,ef").parse::<Vec<String>>(&ctx).unwrap_err();
+    assert_eq!(e.to_string(), "values are too long, expected at most 5 bytes, got 8");
+
+    let ctx = ListCtx { separator: Separator::Whitespace, ..ctx };
+    let mut input = ArgsInput::from("-f abc de -g");
+    let list: Vec<String> = input.parse(&ctx).unwrap();
+    assert_eq!(list, vec!["abc", "de"]);
+    let e = ArgsInput::from("-f abc def").parse::<Vec<String>>(&ctx).unwrap_err();
+    assert_eq!(e.to_string(), "values are too long, expected at most 5 bytes, got 6");
+}
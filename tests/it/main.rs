@@ -1,5 +1,22 @@
 #[macro_use]
 mod macros;
+mod arrays;
+mod auto_help;
 mod bool_argument;
+mod catch_all;
+mod chars;
+mod enum_variants;
+mod flatten;
+mod list_argument;
+mod lists;
+mod named;
+mod net;
+mod numbers;
 mod optional_argument;
+mod positional;
+mod relations;
 mod single_argument;
+mod subcommands;
+mod time;
+mod unknown_arguments;
+mod version;
@@ -0,0 +1,76 @@
+use parkour::prelude::*;
+
+fn parse(s: &'static str) -> parkour::Result<Option<String>> {
+    let mut input = ArgsInput::from(s);
+    let out = input.parse_named(&Flag::LongShort("out", "o"), &Default::default())?;
+    input.expect_empty()?;
+    Ok(out)
+}
+
+#[test]
+fn parse_named() {
+    assert_eq!(parse("-o foo").unwrap(), Some("foo".into()));
+    assert_eq!(parse("-ofoo").unwrap(), Some("foo".into()));
+    assert_eq!(parse("--out=foo").unwrap(), Some("foo".into()));
+
+    let mut input = ArgsInput::from("foo");
+    let out = input.parse_named::<String>(&Flag::Short("o"), &Default::default());
+    assert_eq!(out.unwrap(), None);
+
+    let requires_value = parkour::ErrorInner::RequiresValue { flag: "--out".into() };
+    let e = parse("-o").unwrap_err();
+    assert_eq!(e.inner(), &requires_value);
+    let e = parse("--out --in").unwrap_err();
+    assert_eq!(e.inner(), &requires_value);
+    assert_eq!(e.to_string(), "argument --out requires a value");
+}
+
+#[test]
+fn prefixed_flag() {
+    let flag = Flag::Prefixed("feature", "x");
+    assert_eq!(flag.to_string(), "--feature-x");
+
+    let mut input =
+        ArgsInput::from("--feature-x=1 --feature-x 2 --feature-xy --feature x");
+    let x: Option<u8> = input.parse_named(&flag, &Default::default()).unwrap();
+    assert_eq!(x, Some(1));
+    let x: Option<u8> = input.parse_named(&flag, &Default::default()).unwrap();
+    assert_eq!(x, Some(2));
+    let x: Option<u8> = input.parse_named(&flag, &Default::default()).unwrap();
+    assert_eq!(x, None);
+    assert_eq!(input.bump_argument(), Some("feature-xy"));
+    let x: Option<u8> = input.parse_named(&flag, &Default::default()).unwrap();
+    assert_eq!(x, None);
+}
+
+#[test]
+fn value_with_dashes() {
+    let mut input = ArgsInput::from("-o - -p --x");
+    assert!(input.parse_short_flag("o"));
+    assert_eq!(input.parse_value_ref(), None);
+    assert_eq!(input.parse_value_allow_dashes(), Some("-"));
+    assert!(input.parse_short_flag("p"));
+    assert_eq!(input.parse_value_allow_dashes(), Some("--x"));
+    assert_eq!(input.parse_value_allow_dashes(), None);
+}
+
+#[test]
+fn kv_list() {
+    let flag = Flag::Short("D");
+
+    let mut input = ArgsInput::from("-D a=1,b=2 -D=c=3");
+    let defines: Vec<(String, u32)> = input.parse_kv_list(&flag, '=', ',').unwrap();
+    assert_eq!(defines, vec![("a".to_string(), 1), ("b".to_string(), 2)]);
+    let defines: Vec<(String, u32)> = input.parse_kv_list(&flag, '=', ',').unwrap();
+    assert_eq!(defines, vec![("c".to_string(), 3)]);
+    assert!(input
+        .parse_kv_list::<String, u32>(&flag, '=', ',')
+        .unwrap_err()
+        .is_no_value());
+
+    let mut input = ArgsInput::from("-D a=1,b");
+    let e = input.parse_kv_list::<String, u32>(&flag, '=', ',').unwrap_err();
+    assert_eq!(e.to_string(), "expected `key=value`, got `b`");
+    let sources: Vec<String> = e.sources().map(ToString::to_string).collect();
+    assert_eq!(sources, ["in `-D`", "missing part 1 of value"]);
+}
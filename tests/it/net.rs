@@ -0,0 +1,35 @@
+use std::net::SocketAddr;
+
+use parkour::impls::SocketAddrCtx;
+use parkour::prelude::*;
+
+fn parse(s: &'static str, ctx: SocketAddrCtx) -> parkour::Result<SocketAddr> {
+    let mut input = ArgsInput::from(s);
+    input.parse_value(&ctx)
+}
+
+#[test]
+fn socket_addr() {
+    let addr: SocketAddr = "127.0.0.1:80".parse().unwrap();
+    assert_eq!(parse("127.0.0.1:80", SocketAddrCtx::default()).unwrap(), addr);
+    assert_eq!(
+        parse("127.0.0.1:80", SocketAddrCtx::with_default_port(8080)).unwrap(),
+        addr
+    );
+    assert_eq!(
+        parse("127.0.0.1", SocketAddrCtx::default()).unwrap_err().to_string(),
+        "unexpected value `127.0.0.1`, expected socket address"
+    );
+}
+
+#[test]
+fn default_port() {
+    let ctx = SocketAddrCtx::with_default_port(8080);
+    assert_eq!(parse("127.0.0.1", ctx).unwrap(), "127.0.0.1:8080".parse().unwrap());
+    assert_eq!(parse("[::1]", ctx).unwrap(), "[::1]:8080".parse().unwrap());
+    assert_eq!(parse("::1", ctx).unwrap(), "[::1]:8080".parse().unwrap());
+    assert_eq!(
+        parse("localhost", ctx).unwrap_err().to_string(),
+        "unexpected value `localhost`, expected socket address or IP address"
+    );
+}
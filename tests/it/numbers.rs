@@ -0,0 +1,257 @@
+use std::error::Error as _;
+use std::num::NonZeroU32;
+
+use parkour::prelude::*;
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main)]
+struct Command {
+    #[arg(long, short)]
+    ratio: Option<f64>,
+    #[arg(long, short)]
+    jobs: Option<NonZeroU32>,
+}
+
+macro_rules! ok {
+    ($s:literal, $v:expr) => {
+        assert_parse!(Command, $s, $v)
+    };
+}
+macro_rules! err {
+    ($s:literal, $e:literal) => {
+        assert_parse!(Command, $s, $e)
+    };
+}
+
+#[test]
+fn successes() {
+    ok!("$", Command { ratio: None, jobs: None });
+    ok!("$ --ratio 0.5", Command { ratio: Some(0.5), jobs: None });
+    ok!("$ -r=-1e3", Command { ratio: Some(-1000.0), jobs: None });
+    ok!("$ -j 4", Command { ratio: None, jobs: NonZeroU32::new(4) });
+}
+
+#[test]
+fn failures() {
+    err!("$ --ratio nan", "unexpected value `nan`, expected number: in `--ratio`");
+    err!("$ --ratio inf", "unexpected value `inf`, expected number: in `--ratio`");
+    err!(
+        "$ --ratio=-infinity",
+        "unexpected value `-infinity`, expected number: in `--ratio`"
+    );
+    err!("$ -j 0", "number would be zero for non-zero type: in `--jobs`");
+}
+
+#[test]
+fn non_finite_allowed() {
+    let ctx = NumberCtx { finite: false, ..NumberCtx::default() };
+
+    let mut input = parkour::ArgsInput::from("inf");
+    let n: f64 = input.parse_value(&ctx).unwrap();
+    assert_eq!(n, f64::INFINITY);
+
+    let mut input = parkour::ArgsInput::from("NaN");
+    let n: f32 =
+        input.parse_value(&NumberCtx { finite: false, ..Default::default() }).unwrap();
+    assert!(n.is_nan());
+}
+
+#[test]
+fn validated() {
+    use parkour::impls::{Validated, ValidatedCtx};
+
+    let ctx = ValidatedCtx::new(NumberCtx::default(), |n: &u32| n & 1 == 0);
+
+    let mut input = parkour::ArgsInput::from("4 5");
+    let n: Validated<u32, _> = input.parse_value(&ctx).unwrap();
+    assert_eq!(*n, 4);
+    let e = input.parse_value::<Validated<u32, _>>(&ctx).unwrap_err();
+    assert_eq!(e.to_string(), "unexpected value `5`");
+}
+
+#[test]
+fn int_in_range() {
+    let mut input = parkour::ArgsInput::from("1 10 11 0");
+    assert_eq!(input.parse_int_in_range(1u8, 10).unwrap(), 1);
+    assert_eq!(input.parse_int_in_range(1u8, 10).unwrap(), 10);
+    assert_eq!(
+        input.parse_int_in_range(1u8, 10).unwrap_err().to_string(),
+        "unexpected value `number 11`, expected integer between 1 and 10"
+    );
+    assert!(input.parse_int_in_range(1u8, 10).is_err());
+}
+
+#[test]
+fn structured_range() {
+    use parkour::help::PossibleValues;
+
+    let ctx = NumberCtx::new(1u8, 10);
+    assert_eq!(
+        u8::possible_values(&ctx),
+        Some(PossibleValues::Range {
+            kind: "integer".into(),
+            min: "1".into(),
+            max: "10".into(),
+        })
+    );
+    assert_eq!(
+        i32::possible_values(&NumberCtx { min: 0, ..Default::default() }),
+        Some(PossibleValues::Other("integer at least 0".into()))
+    );
+}
+
+#[test]
+fn collect_all_errors() {
+    let mut input = parkour::ArgsInput::from("$ --ratio x --jobs=0 y");
+    input.set_collect_all_errors(true);
+
+    let e = Command::from_input(&mut input, &()).unwrap_err();
+    assert_eq!(e.to_string(), "3 errors occurred");
+
+    let errors: Vec<String> =
+        input.take_errors().iter().map(ToString::to_string).collect();
+    assert_eq!(
+        errors,
+        vec![
+            "invalid float literal",
+            "number would be zero for non-zero type",
+            "unexpected argument `y`",
+        ]
+    );
+    assert!(input.take_errors().is_empty());
+}
+
+#[test]
+fn collect_all_errors_ignores_earlier_errors() {
+    let mut input = parkour::ArgsInput::from("$ --ratio x --jobs=1");
+    input.set_collect_all_errors(true);
+    input.record_error(parkour::Error::custom("earlier error")).unwrap();
+
+    let e = Command::from_input(&mut input, &()).unwrap_err();
+    assert_eq!(e.to_string(), "1 errors occurred");
+    assert_eq!(input.take_errors().len(), 2);
+}
+
+#[test]
+fn overflow() {
+    let mut input = parkour::ArgsInput::from("300");
+    let e = input.parse_value::<u8>(&Default::default()).unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "unexpected value `number 300`, expected integer between 0 and 255"
+    );
+
+    let mut input = parkour::ArgsInput::from("-1000");
+    let ctx = NumberCtx { max: 100, ..Default::default() };
+    let e = input.parse_value::<i8>(&ctx).unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "unexpected value `number -1000`, expected integer between -128 and 100"
+    );
+
+    let mut input = parkour::ArgsInput::from("200");
+    let e = input.parse_value::<i8>(&Default::default()).unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "unexpected value `number 200`, expected integer between -128 and 127"
+    );
+
+    let mut input = parkour::ArgsInput::from("1x");
+    let e = input.parse_value::<u8>(&Default::default()).unwrap_err();
+    assert_eq!(e.to_string(), "invalid digit found in string");
+}
+
+#[test]
+fn partial_bounds() {
+    assert_eq!(NumberCtx::at_least(5u8), NumberCtx { min: 5, max: 255, finite: true });
+    assert_eq!(NumberCtx::at_most(-1i8), NumberCtx { min: -128, max: -1, finite: true });
+    assert_eq!(NumberCtx::between(1u32, 9), NumberCtx::new(1, 9));
+
+    let mut input = parkour::ArgsInput::from("4 -2");
+    let e = input.parse_value::<u8>(&NumberCtx::at_least(5)).unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "unexpected value `number 4`, expected integer between 5 and 255"
+    );
+    input.bump_argument();
+    assert_eq!(input.parse_value::<i16>(&NumberCtx::at_most(0)).unwrap(), -2);
+}
+
+#[test]
+fn percent() {
+    use parkour::impls::{Percent, PercentCtx};
+
+    let mut input = parkour::ArgsInput::from("50% 12.5 100% 101% -1 x%");
+    let ctx = PercentCtx::default();
+    assert_eq!(input.parse_value::<Percent>(&ctx).unwrap(), Percent(0.5));
+    assert_eq!(input.parse_value::<Percent>(&ctx).unwrap(), Percent(0.125));
+    assert_eq!(input.parse_value::<Percent>(&ctx).unwrap(), Percent(1.0));
+    assert_eq!(
+        input.parse_value::<Percent>(&ctx).unwrap_err().to_string(),
+        "unexpected value `101%`, expected percentage between 0% and 100%"
+    );
+    input.bump_argument();
+    assert!(input.parse_value::<Percent>(&ctx).is_err());
+    input.bump_argument();
+    assert!(input.parse_value::<Percent>(&ctx).is_err());
+
+    let mut input = parkour::ArgsInput::from("50");
+    let ctx = PercentCtx { require_sign: true };
+    assert!(input.parse_value::<Percent>(&ctx).is_err());
+}
+
+#[test]
+fn byte_size() {
+    use parkour::impls::ByteSize;
+
+    let mut input = parkour::ArgsInput::from("512MiB 2GB 10 1kib 30000PB MiB 1.5GB");
+    assert_eq!(input.parse_value::<ByteSize>(&()).unwrap(), ByteSize(536_870_912));
+    assert_eq!(input.parse_value::<ByteSize>(&()).unwrap(), ByteSize(2_000_000_000));
+    assert_eq!(input.parse_value::<ByteSize>(&()).unwrap(), ByteSize(10));
+    assert_eq!(input.parse_value::<ByteSize>(&()).unwrap(), ByteSize(1024));
+    assert_eq!(
+        input.parse_value::<ByteSize>(&()).unwrap_err().to_string(),
+        "unexpected value `size 30000PB`, expected size in bytes, e.g. 512MiB or 2GB"
+    );
+    input.bump_argument();
+    assert!(input.parse_value::<ByteSize>(&()).is_err());
+    input.bump_argument();
+    assert!(input.parse_value::<ByteSize>(&()).is_err());
+}
+
+#[test]
+fn ranges() {
+    use parkour::impls::ParsedRange;
+    use std::ops::{Bound, Range, RangeFrom, RangeInclusive, RangeToInclusive};
+
+    let ctx = NumberCtx::default();
+    let mut input = parkour::ArgsInput::from("1..5 -3..=3 5.. ..=10 .. 7");
+    assert_eq!(input.parse_value::<Range<u8>>(&ctx).unwrap(), 1..5);
+    assert_eq!(
+        input.parse_value::<RangeInclusive<i8>>(&Default::default()).unwrap(),
+        -3..=3
+    );
+    assert_eq!(input.parse_value::<RangeFrom<u8>>(&ctx).unwrap(), 5..);
+    assert_eq!(input.parse_value::<RangeToInclusive<u8>>(&ctx).unwrap(), ..=10);
+    assert_eq!(
+        input.parse_value::<ParsedRange<u8>>(&ctx).unwrap(),
+        ParsedRange { start: Bound::Unbounded, end: Bound::Unbounded }
+    );
+    assert_eq!(
+        input.parse_value::<ParsedRange<u8>>(&ctx).unwrap_err().to_string(),
+        "unexpected value `7`, expected range, e.g. 1..5, 1..=5, 5.. or ..5"
+    );
+
+    let mut input = parkour::ArgsInput::from("..=10 1..5 1..x");
+    let range: ParsedRange<u32> = input.parse_value(&NumberCtx::default()).unwrap();
+    assert_eq!(range, ParsedRange { start: Bound::Unbounded, end: Bound::Included(10) });
+    assert_eq!(
+        input.parse_value::<RangeInclusive<u8>>(&ctx).unwrap_err().to_string(),
+        "unexpected value `1..5`, expected inclusive range, e.g. 1..=5"
+    );
+    input.bump_argument();
+    assert_eq!(
+        input.parse_value::<Range<u8>>(&ctx).unwrap_err().to_string(),
+        "invalid digit found in string"
+    );
+}
@@ -43,7 +43,7 @@ fn successes() {
 
 #[test]
 fn failures() {
-    err!("$ --color", "missing value: in `--color`: in `--color`");
+    err!("$ --color", "argument --color requires a value: in `--color`");
     err!(
         "$ --color=",
         "unexpected value ``, expected `always`, `auto` or `never`: in `--color`"
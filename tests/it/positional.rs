@@ -0,0 +1,91 @@
+use std::error::Error as _;
+
+use parkour::prelude::*;
+
+fn parse(
+    s: &'static str,
+    ctx: &PosCtx<'static, StringCtx>,
+) -> parkour::Result<Vec<String>> {
+    let mut input = ArgsInput::from(s);
+    let mut files = Vec::new();
+    while input.is_not_empty() {
+        if SetPositional(&mut files).apply(&mut input, ctx)? {
+            continue;
+        }
+        input.expect_empty()?;
+    }
+    Ok(files)
+}
+
+#[test]
+fn max_occurrences() {
+    let ctx = PosCtx { max_occurrences: Some(2), ..PosCtx::from("file") };
+    assert_eq!(parse("a b", &ctx).unwrap(), vec!["a", "b"]);
+    assert_eq!(
+        parse("a b c", &ctx).unwrap_err().to_string(),
+        "file was used too often, it can be used at most 2 times"
+    );
+
+    let ctx = PosCtx::from("file");
+    assert_eq!(parse("a b c", &ctx).unwrap(), vec!["a", "b", "c"]);
+}
+
+#[test]
+fn optional_positional() {
+    let ctx = PosCtx::from("file");
+    let mut input = ArgsInput::from("a b");
+    let mut file: Option<String> = None;
+    assert!(SetPositional(&mut file).apply(&mut input, &ctx).unwrap());
+    assert_eq!(
+        SetPositional(&mut file).apply(&mut input, &ctx).unwrap_err().to_string(),
+        "file was used too often, it can be used at most 1 times"
+    );
+}
+
+#[test]
+fn after_double_dash() {
+    let mut input = ArgsInput::from("-- -weird");
+    assert!(input.parse_long_flag(""));
+    input.set_ignore_dashes(true);
+    let value: String = input.parse_value(&StringCtx::default()).unwrap();
+    assert_eq!(value, "-weird");
+    assert!(input.is_empty());
+}
+
+#[test]
+fn unquoted() {
+    let mut input = ArgsInput::from(r#"'foo' "bar" 'baz" ' x"#);
+    let ctx = StringCtx::default();
+    assert_eq!(input.parse_value_unquoted::<String>(&ctx).unwrap(), "foo");
+    assert_eq!(input.parse_value_unquoted::<String>(&ctx).unwrap(), "bar");
+    assert_eq!(input.parse_value_unquoted::<String>(&ctx).unwrap(), "'baz\"");
+    assert_eq!(input.parse_value_unquoted::<String>(&ctx).unwrap(), "'");
+    assert_eq!(input.parse_value_unquoted::<String>(&ctx).unwrap(), "x");
+    assert!(input.is_empty());
+}
+
+#[test]
+fn parse_positional() {
+    let mut input = ArgsInput::from("a.txt -v");
+    let file: Option<String> =
+        input.parse_positional("file", Default::default()).unwrap();
+    assert_eq!(file.as_deref(), Some("a.txt"));
+    assert_eq!(
+        input.parse_positional::<String>("file", Default::default()).unwrap(),
+        None
+    );
+    assert!(input.parse_short_flag("v"));
+
+    let mut input = ArgsInput::from("x");
+    let e = input.parse_positional::<u8>("count", Default::default()).unwrap_err();
+    assert_eq!(e.to_string(), "invalid digit found in string");
+    assert_eq!(e.source().unwrap().to_string(), "in `<count>`");
+}
+
+#[test]
+fn parse_value_map() {
+    let mut input = ArgsInput::from("foo -x");
+    let name = input.parse_value_map(&StringCtx::default(), |s: String| s.to_uppercase());
+    assert_eq!(name.unwrap(), "FOO");
+    assert!(input.parse_value_map(&StringCtx::default(), |s: String| s.len()).is_err());
+}
@@ -0,0 +1,50 @@
+use std::error::Error as _;
+
+use parkour::prelude::*;
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main)]
+struct Command {
+    #[arg(long, short)]
+    #[arg(conflicts_with = "quiet")]
+    verbose: bool,
+    #[arg(long, short)]
+    quiet: bool,
+    #[arg(long, requires = "user")]
+    password: Option<String>,
+    #[arg(long)]
+    user: Option<String>,
+}
+
+macro_rules! ok {
+    ($s:literal, $v:expr) => {
+        assert_parse!(Command, $s, $v)
+    };
+}
+macro_rules! err {
+    ($s:literal, $e:literal) => {
+        assert_parse!(Command, $s, $e)
+    };
+}
+
+#[test]
+fn successes() {
+    ok!("$ -v", Command { verbose: true, quiet: false, password: None, user: None });
+    ok!("$ -q", Command { verbose: false, quiet: true, password: None, user: None });
+    ok!(
+        "$ --password x --user me",
+        Command {
+            verbose: false,
+            quiet: false,
+            password: Some("x".to_string()),
+            user: Some("me".to_string()),
+        }
+    );
+}
+
+#[test]
+fn failures() {
+    err!("$ -v -q", "argument --verbose cannot be used with --quiet");
+    err!("$ -q --verbose", "argument --verbose cannot be used with --quiet");
+    err!("$ --password x", "required --user was not provided: in `--password`");
+}
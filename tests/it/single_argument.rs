@@ -43,7 +43,7 @@ fn successes() {
 #[test]
 fn failures() {
     err!("$", "required --color was not provided");
-    err!("$ --color", "missing value: in `--color`: in `--color`");
+    err!("$ --color", "argument --color requires a value: in `--color`");
     err!(
         "$ --color=",
         "unexpected value ``, expected `always`, `auto` or `never`: in `--color`"
@@ -68,3 +68,134 @@ fn failures() {
         "--alias was used too often, it can be used at most 1 times"
     );
 }
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main)]
+struct Multiple {
+    #[arg(long, short, multiple)]
+    mode: ColorMode,
+    #[arg(long, multiple)]
+    level: Option<u8>,
+    #[arg(short, multiple)]
+    verbose: bool,
+}
+
+#[test]
+fn multiple() {
+    assert_parse!(
+        Multiple,
+        "$ -m always --mode never --level 1 --level=2 -v -v",
+        Multiple { mode: ColorMode::Never, level: Some(2), verbose: true }
+    );
+    assert_parse!(Multiple, "$ --level 1", "required --mode was not provided");
+}
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main)]
+struct Skipped {
+    #[arg(long, short)]
+    mode: ColorMode,
+    #[parkour(skip)]
+    _marker: std::marker::PhantomData<u8>,
+    #[parkour(skip)]
+    computed: Vec<String>,
+}
+
+#[test]
+fn skip() {
+    assert_parse!(
+        Skipped,
+        "$ -m auto",
+        Skipped {
+            mode: ColorMode::Auto,
+            _marker: std::marker::PhantomData,
+            computed: vec![],
+        }
+    );
+    assert_parse!(Skipped, "$ -m auto --computed x", "unexpected argument `computed`");
+}
+
+#[derive(FromInputValue, Debug, PartialEq)]
+enum When {
+    #[parkour(alias = "a")]
+    Always,
+    #[parkour(default, alias = "detect")]
+    Auto,
+    Never,
+}
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main)]
+struct Defaulted {
+    #[arg(long)]
+    color: Option<When>,
+}
+
+#[test]
+fn default_variant() {
+    assert_parse!(Defaulted, "$ --color=", Defaulted { color: Some(When::Auto) });
+    assert_parse!(Defaulted, "$ --color=never", Defaulted { color: Some(When::Never) });
+    assert_parse!(Defaulted, "$ --color auto", Defaulted { color: Some(When::Auto) });
+    assert_parse!(Defaulted, "$", Defaulted { color: None });
+}
+
+#[test]
+fn variant_alias() {
+    assert_parse!(Defaulted, "$ --color a", Defaulted { color: Some(When::Always) });
+    assert_parse!(Defaulted, "$ --color=A", Defaulted { color: Some(When::Always) });
+    assert_parse!(Defaulted, "$ --color detect", Defaulted { color: Some(When::Auto) });
+    assert_parse!(
+        Defaulted,
+        "$ --color x",
+        "unexpected value `x`, expected `always`, `auto` or `never`: in `--color`"
+    );
+}
+
+#[derive(FromInputValue, Debug, PartialEq)]
+#[parkour(numeric)]
+enum Level {
+    Low,
+    Mid,
+    High,
+}
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main)]
+struct Leveled {
+    #[arg(long)]
+    level: Level,
+}
+
+#[test]
+fn numeric_variants() {
+    assert_parse!(Leveled, "$ --level 2", Leveled { level: Level::High });
+    assert_parse!(Leveled, "$ --level=0", Leveled { level: Level::Low });
+    assert_parse!(Leveled, "$ --level mid", Leveled { level: Level::Mid });
+    assert_parse!(
+        Leveled,
+        "$ --level 3",
+        "unexpected value `3`, expected `low`, `mid` or `high`: in `--level`"
+    );
+}
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main)]
+struct Paths {
+    #[arg(long)]
+    path: Option<std::path::PathBuf>,
+    #[arg(long)]
+    name: Option<std::ffi::OsString>,
+}
+
+#[test]
+#[cfg(unix)]
+fn non_utf8_values() {
+    use std::ffi::OsString;
+    use std::os::unix::ffi::OsStringExt;
+
+    let raw = OsString::from_vec(vec![b'a', 0xff]);
+    let args =
+        vec!["$".into(), "--path".into(), raw.clone(), "--name".into(), raw.clone()];
+    let paths = Paths::from_input(&mut ArgsInput::from_os_args(args), &()).unwrap();
+    assert_eq!(paths, Paths { path: Some(raw.clone().into()), name: Some(raw) });
+}
@@ -0,0 +1,197 @@
+use std::error::Error as _;
+
+use parkour::prelude::*;
+
+#[derive(Debug, PartialEq)]
+struct Command {
+    show: Option<Show>,
+}
+
+#[derive(FromInput, Debug, Clone, PartialEq)]
+#[parkour(subcommand)]
+struct Show {
+    #[arg(long)]
+    id: usize,
+}
+
+impl FromInput<'static> for Command {
+    type Context = ();
+
+    fn from_input(input: &mut ArgsInput, _: &()) -> parkour::Result<Self> {
+        input.bump_argument().unwrap();
+        let show = input.parse_subcommand("show", &())?;
+        input.expect_empty()?;
+        Ok(Command { show })
+    }
+}
+
+macro_rules! ok {
+    ($s:literal, $v:expr) => {
+        assert_parse!(Command, $s, $v)
+    };
+}
+macro_rules! err {
+    ($s:literal, $e:literal) => {
+        assert_parse!(Command, $s, $e)
+    };
+}
+
+#[test]
+fn successes() {
+    ok!("$", Command { show: None });
+    ok!("$ show --id 5", Command { show: Some(Show { id: 5 }) });
+}
+
+#[test]
+fn failures() {
+    err!("$ show", "required --id was not provided: in subcommand show");
+    err!("$ show --id x", "invalid digit found in string: in subcommand show: in `--id`");
+}
+
+#[test]
+fn tolerant_subcommand() {
+    let mut input = ArgsInput::from("show --id x");
+    let mut show: Option<Result<Show, parkour::Error>> = None;
+    assert!(SetSubcommand(&mut show).apply(&mut input, &()).unwrap());
+
+    let e = show.unwrap().unwrap_err();
+    assert_eq!(e.to_string(), "invalid digit found in string");
+
+    let mut input = ArgsInput::from("show --id 5");
+    let show: Option<Result<Show, parkour::Error>> = input.try_parse(&()).unwrap();
+    assert_eq!(show.unwrap().unwrap(), Show { id: 5 });
+
+    let mut input = ArgsInput::from("hide");
+    let show: Option<Result<Show, parkour::Error>> = input.try_parse(&()).unwrap();
+    assert!(show.is_none());
+}
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(subcommand)]
+struct Build {
+    #[arg(long)]
+    release: bool,
+}
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main)]
+enum Cargo {
+    Show(Show),
+    Build(Build),
+}
+
+#[test]
+fn failed_attempt_consumes_nothing() {
+    let mut input = ArgsInput::from("build --release");
+    assert!(!input.parse_command("show"));
+    assert!(Show::try_from_input(&mut input, &()).unwrap().is_none());
+    assert_eq!(Build::from_input(&mut input, &()).unwrap(), Build { release: true });
+    assert!(input.is_empty());
+
+    assert_parse!(Cargo, "$ build --release", Cargo::Build(Build { release: true }));
+    assert_parse!(Cargo, "$ show --id 3", Cargo::Show(Show { id: 3 }));
+}
+
+#[test]
+fn command_chain() {
+    let mut input = ArgsInput::from("remote add origin url");
+    assert!(!input.parse_command_chain(&["branch", "add"]));
+    assert!(input.parse_command_chain(&["remote", "add"]));
+    assert_eq!(input.parse_value_ref(), Some("origin"));
+    assert_eq!(input.parse_value_ref(), Some("url"));
+    assert!(input.parse_command_chain(&[]));
+
+    let mut input = ArgsInput::from("remote remove origin");
+    assert!(!input.parse_command_chain(&["remote", "add"]));
+    assert!(!input.parse_command_chain(&["remote", "remove", "origin", "x"]));
+    assert!(input.parse_command_chain(&["remote", "remove"]));
+    assert_eq!(input.parse_value_ref(), Some("origin"));
+}
+
+#[test]
+fn peek_command() {
+    let mut input = ArgsInput::from("show --x");
+    assert_eq!(input.peek_command(), Some("show"));
+    assert!(input.parse_command("show"));
+    assert_eq!(input.peek_command(), None);
+    assert!(input.parse_long_flag("x"));
+    assert_eq!(input.peek_command(), None);
+}
+
+#[test]
+fn inherit_ignore_dashes() {
+    let mut input = ArgsInput::from("-- show --id");
+    assert!(input.parse_long_flag(""));
+    input.set_ignore_dashes(true);
+
+    // `--id` is a positional argument, so it isn't recognized as a flag
+    let e = Show::from_input(&mut input, &()).unwrap_err();
+    assert_eq!(e.to_string(), "unexpected argument `--id`");
+    assert!(input.ignore_dashes());
+
+    let mut input = ArgsInput::from("show -- --id");
+    let e = Show::from_input(&mut input, &()).unwrap_err();
+    assert_eq!(e.to_string(), "unexpected argument `--id`");
+    assert!(input.ignore_dashes());
+}
+
+#[derive(FromInput, Debug, Clone, PartialEq)]
+#[parkour(main)]
+struct App {
+    #[arg(long, short, global)]
+    verbose: bool,
+    #[arg(long)]
+    color: bool,
+    #[arg(subcommand)]
+    command: Option<Show>,
+}
+
+#[test]
+fn global_flags() {
+    let show = Some(Show { id: 5 });
+    let app = App { verbose: true, color: false, command: show.clone() };
+    assert_parse!(App, "$ show --id 5 --verbose", app.clone());
+    assert_parse!(App, "$ --verbose show --id 5", app.clone());
+    assert_parse!(App, "$ show -v --id 5", app);
+
+    let app = App { verbose: false, color: true, command: show };
+    assert_parse!(App, "$ --color show --id 5", app);
+    assert_parse!(
+        App,
+        "$ show --id 5 --color",
+        "unexpected argument `color`: in subcommand show"
+    );
+    assert_parse!(App, "$ -v", App { verbose: true, color: false, command: None });
+}
+
+#[test]
+fn global_flags_keep_stashed_arguments() {
+    // `-v` was stashed by the caller, so it must not be parsed as `--verbose`
+    let mut input = ArgsInput::from("-v $ show --id 5 --y");
+    input.set_collect_unknown(true);
+    assert_eq!(input.stash_unknown(), Some("-v"));
+
+    let app = App::from_input(&mut input, &()).unwrap();
+    assert_eq!(app, App { verbose: false, color: false, command: Some(Show { id: 5 }) });
+    assert!(input.collect_unknown());
+    assert_eq!(input.take_unknown(), vec!["-v", "--y"]);
+}
+
+#[test]
+fn flag_error_before_subcommand() {
+    let mut input = ArgsInput::from("$ -v -v show");
+    input.set_collect_all_errors(true);
+
+    let e = App::from_input(&mut input, &()).unwrap_err();
+    assert_eq!(e.to_string(), "2 errors occurred");
+
+    let errors: Vec<String> =
+        input.take_errors().iter().map(ToString::to_string).collect();
+    assert_eq!(
+        errors,
+        vec![
+            "--verbose was used too often, it can be used at most 1 times",
+            "required --id was not provided",
+        ]
+    );
+}
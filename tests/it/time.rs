@@ -0,0 +1,33 @@
+use std::error::Error as _;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use parkour::impls::TimestampCtx;
+use parkour::prelude::*;
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main)]
+struct Command {
+    #[arg(long)]
+    since: Option<SystemTime>,
+}
+
+#[test]
+fn seconds() {
+    assert_parse!(
+        Command,
+        "$ --since 1700000000",
+        Command { since: Some(UNIX_EPOCH + Duration::from_secs(1_700_000_000)) }
+    );
+    assert_parse!(
+        Command,
+        "$ --since yesterday",
+        "unexpected value `yesterday`, expected unix timestamp in seconds: in `--since`"
+    );
+}
+
+#[test]
+fn millis() {
+    let mut input = ArgsInput::from("1700000000123");
+    let time: SystemTime = input.parse_value(&TimestampCtx::Millis).unwrap();
+    assert_eq!(time, UNIX_EPOCH + Duration::from_millis(1_700_000_000_123));
+}
@@ -0,0 +1,46 @@
+use parkour::prelude::*;
+
+#[derive(FromInput, Debug, PartialEq)]
+#[parkour(main)]
+struct Command {
+    #[arg(long)]
+    known: Option<String>,
+}
+
+#[test]
+fn collect_unknown() {
+    let mut input = ArgsInput::from("$ --known x --unknown y");
+    input.set_collect_unknown(true);
+
+    let command = Command::from_input(&mut input, &()).unwrap();
+    assert_eq!(command, Command { known: Some("x".into()) });
+    assert_eq!(input.take_unknown(), vec!["--unknown", "y"]);
+}
+
+#[test]
+fn collect_unknown_disabled() {
+    let mut input = ArgsInput::from("$ --known x --unknown y");
+
+    let error = Command::from_input(&mut input, &()).unwrap_err();
+    assert_eq!(error.to_string(), "unexpected argument `unknown`");
+}
+
+#[test]
+fn report_all_unexpected() {
+    let mut input = ArgsInput::from("$ foo bar baz");
+    input.bump_argument();
+
+    let error = input.save_and_report_unexpected().unwrap_err();
+    assert_eq!(error.to_string(), "unexpected arguments `foo`, `bar` and `baz`");
+    assert!(input.is_empty());
+    assert!(input.save_and_report_unexpected().is_ok());
+}
+
+#[test]
+fn report_all_unexpected_with_dashes() {
+    let mut input = ArgsInput::from("$ foo --bar -b");
+    input.bump_argument();
+
+    let error = input.save_and_report_unexpected().unwrap_err();
+    assert_eq!(error.to_string(), "unexpected arguments `foo`, `--bar` and `-b`");
+}
@@ -0,0 +1,60 @@
+use parkour::prelude::*;
+
+#[derive(Debug, PartialEq)]
+struct Command {
+    verbose: bool,
+}
+
+impl FromInput<'static> for Command {
+    type Context = ();
+
+    fn from_input(input: &mut ArgsInput, _: &()) -> parkour::Result<Self> {
+        input.bump_argument().unwrap();
+        let mut verbose = false;
+
+        while input.is_not_empty() {
+            input.parse_version_flag("command 1.0.0")?;
+
+            if input.parse_long_flag("verbose") {
+                verbose = true;
+                continue;
+            }
+            input.expect_empty()?;
+        }
+        Ok(Command { verbose })
+    }
+}
+
+#[test]
+fn version_flag() {
+    for &s in &["$ -V", "$ --version", "$ --verbose -V"] {
+        let e = Command::from_input(&mut ArgsInput::from(s), &()).unwrap_err();
+        assert!(e.is_version());
+        assert!(!e.is_early_exit());
+        assert_eq!(e.to_string(), "command 1.0.0");
+    }
+
+    let command = Command::from_input(&mut ArgsInput::from("$ --verbose"), &()).unwrap();
+    assert_eq!(command, Command { verbose: true });
+
+    let e = Command::from_input(&mut ArgsInput::from("$ --version=1"), &()).unwrap_err();
+    assert_eq!(e.to_string(), "unexpected value `1`");
+}
+
+#[test]
+fn help_topic() {
+    let mut input = ArgsInput::from("--help -h --help= --help=net -hx --verbose");
+    assert!(input.parse_help_flag().unwrap());
+    assert!(input.parse_help_flag().unwrap());
+    assert!(input.parse_help_flag().unwrap());
+
+    let e = input.parse_help_flag().unwrap_err();
+    assert!(e.is_help_topic());
+    assert!(e.kind_eq(&parkour::ErrorInner::HelpTopic("net".into())));
+    assert_eq!(e.to_string(), "help for topic `net` was requested");
+
+    let e = input.parse_help_flag().unwrap_err();
+    assert_eq!(e.to_string(), "unexpected value `x`");
+    assert!(!input.parse_help_flag().unwrap());
+    assert!(input.parse_long_flag("verbose"));
+}